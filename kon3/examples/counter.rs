@@ -13,13 +13,10 @@ fn main() {
     let element = column((
         '_counter: { label(concat(("clicked ", strfy(counter.clone()), " times"))) },
         '_button: {
-            on_click(
-                layers((rect(Color::GREEN), label("click me!"))),
-                move |signaler| {
-                    *counter.write(signaler) += 1;
-                    Consume
-                },
-            )
+            button(label("click me!"), move |signaler| {
+                *counter.write(signaler) += 1;
+                Consume
+            })
         },
     ));
 
@@ -1,16 +1,18 @@
+use super::Signal;
 use super::Signaler;
+use crate::dispatch_event;
+use crate::draw_frame_dirty;
 use crate::resources::Resources;
-use crate::DrawPass;
 use crate::Drawers;
 use crate::Element;
 use crate::Event;
+use crate::EventResult;
 use crate::LocationPoint;
 use crate::LocationRect;
 use std::collections::HashMap;
 use std::sync::Arc;
 use sww::app::EventHandler as SwwEventHandler;
 use sww::dvec2;
-use sww::wgpu;
 use sww::window::event::*;
 use sww::window::*;
 use sww::DVec2;
@@ -21,6 +23,7 @@ pub struct EventHandler<E: Element> {
     resources: Resources,
     drawers: Drawers,
     cursor_positions: HashMap<DeviceId, DVec2>,
+    modifiers: KeyboardModifiers,
     signaler: Signaler,
 }
 
@@ -39,6 +42,7 @@ impl<E: Element> EventHandler<E> {
             resources,
             drawers,
             cursor_positions,
+            modifiers: Default::default(),
             signaler,
         }
     }
@@ -50,6 +54,54 @@ impl<E: Element> EventHandler<E> {
     pub fn signaler(&self) -> &Signaler {
         &self.signaler
     }
+
+    fn location_point(&self, window_point: DVec2) -> LocationPoint {
+        LocationPoint::from_window_point(window_point, self.rw.window().inner_size())
+    }
+
+    fn dispatch(&self, event: Event) -> EventResult {
+        let location = LocationRect::new(self.rw.window().inner_size());
+        dispatch_event(&self.element, &self.signaler, &event, location)
+    }
+
+    fn handle_key(&self, event: KeyEvent) {
+        let pressed = event.state == ElementState::Pressed;
+        let is_tab = event.logical_key == Key::Named(NamedKey::Tab);
+        let text = (event.text.as_ref())
+            .map(|text| text.chars().filter(|c| !c.is_control()).collect::<String>());
+
+        let modifiers = self.modifiers;
+        if !pressed || self.dispatch(Event::Key { event, modifiers }).is_err() {
+            return;
+        }
+
+        if is_tab {
+            self.move_focus(modifiers.state().shift_key());
+        } else if let Some(text) = text.filter(|text| !text.is_empty()) {
+            if self.signaler.focused().is_some() {
+                _ = self.dispatch(Event::Text(text));
+            }
+        }
+    }
+
+    fn move_focus(&self, backwards: bool) {
+        let mut order = Vec::new();
+        self.element.focus_order(&mut order);
+        if order.is_empty() {
+            return;
+        }
+
+        let current = (self.signaler.focused())
+            .and_then(|focused| order.iter().position(|&id| id == focused));
+        let next = match (current, backwards) {
+            (None, false) => 0,
+            (None, true) => order.len() - 1,
+            (Some(current), false) => (current + 1) % order.len(),
+            (Some(current), true) => (current + order.len() - 1) % order.len(),
+        };
+
+        self.signaler.set_focus(Some(order[next]));
+    }
 }
 
 impl<E: Element> SwwEventHandler for EventHandler<E> {
@@ -62,45 +114,40 @@ impl<E: Element> SwwEventHandler for EventHandler<E> {
         match event {
             WindowEvent::Resized(new_size) => {
                 self.rw.resize_surface(new_size);
+                self.signaler.send(Signal::Redraw);
             }
 
             WindowEvent::RedrawRequested => {
                 let mut frame = self.rw.start_drawing();
-                let (commands, surface) = frame.commands_surface();
-                let mut render_pass =
-                    (commands.encoder()).begin_render_pass(&wgpu::RenderPassDescriptor {
-                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view: surface.view(),
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                                store: wgpu::StoreOp::Store,
-                            },
-                        })],
-                        ..Default::default()
-                    });
-
-                let mut pass = {
-                    let rw = Arc::clone(&self.rw);
-                    DrawPass::new(rw, &mut render_pass, &mut self.drawers)
-                };
-                let location = {
-                    let window_size = self.rw.window().inner_size();
-                    LocationRect::new(window_size)
-                };
-
-                self.element.draw(&mut pass, &self.resources, location);
+                let rw = Arc::clone(&self.rw);
+                let dirty_rects = self.signaler.take_dirty_rects();
+                draw_frame_dirty(
+                    rw,
+                    &mut frame,
+                    &mut self.drawers,
+                    &self.resources,
+                    &self.element,
+                    dirty_rects.as_deref(),
+                );
             }
 
             WindowEvent::CursorMoved {
                 device_id,
                 position: FloatPosition { x, y },
             } => {
-                self.cursor_positions.insert(device_id, dvec2(x, y));
+                let previous = self.cursor_positions.insert(device_id, dvec2(x, y));
+                _ = self.dispatch(Event::PointerMove {
+                    point: self.location_point(dvec2(x, y)),
+                    previous: previous.map(|previous| self.location_point(previous)),
+                });
             }
 
             WindowEvent::CursorLeft { device_id } => {
-                self.cursor_positions.remove(&device_id);
+                if let Some(position) = self.cursor_positions.remove(&device_id) {
+                    _ = self.dispatch(Event::PointerLeave {
+                        point: self.location_point(position),
+                    });
+                }
             }
 
             WindowEvent::MouseInput {
@@ -109,19 +156,51 @@ impl<E: Element> SwwEventHandler for EventHandler<E> {
                 button,
             } => {
                 if let Some(position) = self.cursor_positions.get(&device_id).copied() {
-                    let event = match state {
-                        ElementState::Released => Event::Click {
-                            point: LocationPoint::new(position, self.rw.window().inner_size()),
-                            button,
-                        },
+                    let point = self.location_point(position);
+                    match state {
+                        ElementState::Pressed => {
+                            _ = self.dispatch(Event::PointerDown { point, button });
+                        }
 
-                        _ => return, // FIXME
-                    };
+                        ElementState::Released => {
+                            _ = self.dispatch(Event::PointerUp { point, button });
+                            _ = self.dispatch(Event::Click { point, button });
+                        }
+                    }
+                }
+            }
 
-                    _ = self.element.handle_event(&self.signaler, &event);
+            WindowEvent::MouseWheel {
+                device_id, delta, ..
+            } => {
+                if let Some(position) = self.cursor_positions.get(&device_id).copied() {
+                    _ = self.dispatch(Event::Scroll {
+                        point: self.location_point(position),
+                        delta,
+                    });
                 }
             }
 
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers;
+            }
+
+            WindowEvent::KeyboardInput {
+                event,
+                is_synthetic: false,
+                ..
+            } => {
+                self.handle_key(event);
+            }
+
+            WindowEvent::Ime(Ime::Commit(text)) => {
+                _ = self.dispatch(Event::Text(text));
+            }
+
+            WindowEvent::Ime(ime) => {
+                _ = self.dispatch(Event::Ime(ime));
+            }
+
             WindowEvent::CloseRequested => {
                 event_loop.exit();
             }
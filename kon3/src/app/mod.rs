@@ -3,10 +3,12 @@ use crate::shared::SharedAddr;
 use crate::Drawers;
 use crate::Element;
 use crate::Event;
+use crate::LocationRect;
 use std::collections::BTreeSet;
 use std::sync::mpsc::channel;
 use std::sync::mpsc::Receiver;
 use std::sync::Arc;
+use std::time::Instant;
 use sww::app::app_new as sww_app_new;
 use sww::app::App as SwwApp;
 use sww::app::EventHandlerBuilder;
@@ -17,14 +19,17 @@ use sww::ApplicationHandler;
 
 mod event_handler;
 mod signals;
+mod timers;
 
 use event_handler::*;
 pub use signals::*;
+pub use timers::*;
 
 pub struct App<WIB: RenderWindowBuilder, E: Element, EB: EventHandlerBuilder<EventHandler<E>>> {
     app: SwwApp<WIB, EventHandler<E>, EB>,
     signal_receiver: Receiver<Signal>,
     updated_shareds: BTreeSet<SharedAddr>,
+    animation_frame_requested: bool,
 }
 
 pub fn app<E: Element + 'static>(
@@ -32,7 +37,7 @@ pub fn app<E: Element + 'static>(
     settings: impl WindowSettings + 'static,
 ) -> App<impl RenderWindowBuilder, E, impl EventHandlerBuilder<EventHandler<E>>> {
     let (signaler, signal_receiver) = channel();
-    let signaler = Signaler(signaler);
+    let signaler = Signaler::new(signaler);
 
     let window_attributes = settings.window_attributes();
     let app = sww_app_new(
@@ -46,7 +51,7 @@ pub fn app<E: Element + 'static>(
             EventHandler::new(
                 Arc::clone(rw),
                 element,
-                Resources::new(Arc::clone(rw)),
+                Resources::new(Arc::clone(rw) as _),
                 Drawers::default(),
                 Default::default(),
                 signaler,
@@ -58,6 +63,7 @@ pub fn app<E: Element + 'static>(
         app,
         signal_receiver,
         updated_shareds: Default::default(),
+        animation_frame_requested: false,
     }
 }
 
@@ -74,9 +80,25 @@ impl<WIB: RenderWindowBuilder, E: Element, EB: EventHandlerBuilder<EventHandler<
         window_id: WindowId,
         event: WindowEvent,
     ) {
+        if matches!(event, WindowEvent::RedrawRequested) && self.animation_frame_requested {
+            self.animation_frame_requested = false;
+            self.dispatch_animation_frame();
+        }
+
         self.app.window_event(event_loop, window_id, event);
         self.handle_signals();
     }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.fire_timers();
+
+        let next_deadline = (self.app.event_handler())
+            .and_then(|event_handler| event_handler.signaler().next_timer_deadline());
+        event_loop.set_control_flow(match next_deadline {
+            Some(deadline) => ControlFlow::WaitUntil(deadline),
+            None => ControlFlow::Wait,
+        });
+    }
 }
 
 impl<WIB: RenderWindowBuilder, E: Element, EB: EventHandlerBuilder<EventHandler<E>>>
@@ -102,6 +124,24 @@ impl<WIB: RenderWindowBuilder, E: Element, EB: EventHandlerBuilder<EventHandler<
                     Signal::SharedUpdated(addr) => {
                         self.updated_shareds.insert(addr);
                     }
+
+                    Signal::AnimationFrame => {
+                        self.animation_frame_requested = true;
+                        self.app.rw().unwrap().window().request_redraw();
+                    }
+
+                    Signal::FocusChanged(focus) => {
+                        let rw = self.app.rw().unwrap();
+                        rw.window().set_ime_allowed(focus.is_some());
+
+                        let location = LocationRect::new(rw.size());
+                        let event_handler = self.app.event_handler().unwrap();
+                        _ = (event_handler.element()).handle_event(
+                            event_handler.signaler(),
+                            &Event::FocusChanged(focus),
+                            location,
+                        );
+                    }
                 }
             }
 
@@ -112,11 +152,41 @@ impl<WIB: RenderWindowBuilder, E: Element, EB: EventHandlerBuilder<EventHandler<
 
             let element = &self.app.event_handler().unwrap().element();
             let signaler = &self.app.event_handler().unwrap().signaler();
+            let location = LocationRect::new(self.app.rw().unwrap().size());
 
             for addr in updated_shareds {
-                _ = element.handle_event(signaler, &Event::SharedUpdated(addr));
+                _ = element.handle_event(signaler, &Event::SharedUpdated(addr), location);
             }
             self.updated_shareds.clear();
         }
     }
+
+    fn fire_timers(&mut self) {
+        let Some(event_handler) = self.app.event_handler() else {
+            return;
+        };
+        let signaler = event_handler.signaler();
+        let due = signaler.take_due_timers(Instant::now());
+        if due.is_empty() {
+            return;
+        }
+
+        let location = LocationRect::new(self.app.rw().unwrap().size());
+        for id in due {
+            _ = (event_handler.element()).handle_event(signaler, &Event::Timer(id), location);
+        }
+        self.handle_signals();
+    }
+
+    fn dispatch_animation_frame(&self) {
+        let Some(event_handler) = self.app.event_handler() else {
+            return;
+        };
+        let location = LocationRect::new(self.app.rw().unwrap().size());
+        _ = (event_handler.element()).handle_event(
+            event_handler.signaler(),
+            &Event::AnimationFrame(Instant::now()),
+            location,
+        );
+    }
 }
@@ -1,16 +1,148 @@
+use super::timers::Timers;
+use super::TimerId;
 use crate::shared::SharedAddr;
+use crate::LocationRect;
+use parking_lot::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::Sender;
+use std::time::Duration;
+use std::time::Instant;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Signal {
     Redraw,
     SharedUpdated(SharedAddr),
+    FocusChanged(Option<FocusId>),
+    /// Requests [`Event::AnimationFrame`] before the next frame is drawn.
+    ///
+    /// [`Event::AnimationFrame`]: crate::Event::AnimationFrame
+    AnimationFrame,
 }
 
-pub struct Signaler(pub(super) Sender<Signal>);
+/// Identifies an element that can receive keyboard input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FocusId(u64);
+
+impl FocusId {
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for FocusId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parts of the window to redraw.
+#[derive(Default)]
+struct Redraws {
+    /// Set by redraws requested outside of any [`Signaler::redraw_scope`].
+    full: bool,
+    rects: Vec<LocationRect>,
+    /// Locations of the active scopes, innermost last, and whether each requested a redraw.
+    scopes: Vec<(LocationRect, bool)>,
+}
+
+pub struct Signaler {
+    sender: Sender<Signal>,
+    focused: Mutex<Option<FocusId>>,
+    redraws: Mutex<Redraws>,
+    timers: Mutex<Timers>,
+}
 
 impl Signaler {
+    pub(crate) fn new(sender: Sender<Signal>) -> Self {
+        Self {
+            sender,
+            focused: Default::default(),
+            redraws: Default::default(),
+            timers: Default::default(),
+        }
+    }
+
     pub fn send(&self, signal: Signal) {
-        self.0.send(signal).unwrap();
+        if signal == Signal::Redraw {
+            let mut redraws = self.redraws.lock();
+            let Redraws {
+                full,
+                rects,
+                scopes,
+            } = &mut *redraws;
+            // enclosing scopes include what the innermost one draws, so they're stale as well
+            match scopes.last() {
+                Some(&(location, _)) => rects.push(location),
+                None => *full = true,
+            }
+            for (_, requested) in scopes {
+                *requested = true;
+            }
+        }
+        self.sender.send(signal).unwrap();
+    }
+
+    /// Runs `f`, limiting the redraws it requests to `location`, and returns whether it requested
+    /// any.
+    pub fn redraw_scope<R>(&self, location: LocationRect, f: impl FnOnce() -> R) -> (R, bool) {
+        self.redraws.lock().scopes.push((location, false));
+        let result = f();
+        let (_, requested) = self.redraws.lock().scopes.pop().unwrap();
+        (result, requested)
+    }
+
+    /// Takes the parts of the window to redraw, or `None` if all of it has to be redrawn.
+    pub fn take_dirty_rects(&self) -> Option<Vec<LocationRect>> {
+        let mut redraws = self.redraws.lock();
+        let rects = std::mem::take(&mut redraws.rects);
+        let full = std::mem::take(&mut redraws.full);
+        (!full).then_some(rects)
+    }
+
+    /// Sends [`Event::Timer`] with the returned id once, after `delay`.
+    ///
+    /// [`Event::Timer`]: crate::Event::Timer
+    pub fn set_timeout(&self, delay: Duration) -> TimerId {
+        self.timers.lock().insert(delay, None)
+    }
+
+    /// Sends [`Event::Timer`] with the returned id every `interval`, until it's cleared.
+    /// Intervals shorter than [`MIN_INTERVAL`] are raised to it.
+    ///
+    /// [`Event::Timer`]: crate::Event::Timer
+    /// [`MIN_INTERVAL`]: super::MIN_INTERVAL
+    pub fn set_interval(&self, interval: Duration) -> TimerId {
+        self.timers.lock().insert(interval, Some(interval))
+    }
+
+    pub fn clear_timer(&self, id: TimerId) {
+        self.timers.lock().remove(id);
+    }
+
+    pub(super) fn next_timer_deadline(&self) -> Option<Instant> {
+        self.timers.lock().next_deadline()
+    }
+
+    pub(super) fn take_due_timers(&self, now: Instant) -> Vec<TimerId> {
+        self.timers.lock().take_due(now)
+    }
+
+    pub fn focused(&self) -> Option<FocusId> {
+        *self.focused.lock()
+    }
+
+    pub fn is_focused(&self, id: FocusId) -> bool {
+        self.focused() == Some(id)
+    }
+
+    pub fn set_focus(&self, focus: Option<FocusId>) {
+        let mut focused = self.focused.lock();
+        if *focused != focus {
+            *focused = focus;
+            self.send(Signal::FocusChanged(focus));
+            self.send(Signal::Redraw);
+        }
     }
 }
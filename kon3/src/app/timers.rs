@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Shortest interval of a repeating timer, so that it can't keep the event loop busy.
+pub const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Identifies a timer started with [`Signaler::set_timeout`] or [`Signaler::set_interval`].
+///
+/// [`Signaler::set_timeout`]: super::Signaler::set_timeout
+/// [`Signaler::set_interval`]: super::Signaler::set_interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(u64);
+
+impl TimerId {
+    fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+struct Timer {
+    deadline: Instant,
+    interval: Option<Duration>,
+}
+
+#[derive(Default)]
+pub(super) struct Timers(BTreeMap<TimerId, Timer>);
+
+impl Timers {
+    pub fn insert(&mut self, delay: Duration, interval: Option<Duration>) -> TimerId {
+        let id = TimerId::new();
+        let deadline = Instant::now() + delay;
+        let interval = interval.map(|interval| interval.max(MIN_INTERVAL));
+        self.0.insert(id, Timer { deadline, interval });
+        id
+    }
+
+    pub fn remove(&mut self, id: TimerId) {
+        self.0.remove(&id);
+    }
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.0.values().map(|timer| timer.deadline).min()
+    }
+
+    /// Removes due timeouts and reschedules due intervals, returning both in order of deadline.
+    pub fn take_due(&mut self, now: Instant) -> Vec<TimerId> {
+        let mut due = (self.0.iter())
+            .filter(|(_, timer)| timer.deadline <= now)
+            .map(|(&id, timer)| (timer.deadline, id))
+            .collect::<Vec<_>>();
+        due.sort();
+
+        for &(_, id) in &due {
+            let timer = self.0.get_mut(&id).unwrap();
+            match timer.interval {
+                Some(interval) => {
+                    // ticks missed while the app was busy are skipped
+                    timer.deadline += interval;
+                    if timer.deadline <= now {
+                        timer.deadline = now + interval;
+                    }
+                }
+                None => {
+                    self.0.remove(&id);
+                }
+            }
+        }
+
+        due.into_iter().map(|(_, id)| id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_due_orders_by_deadline() {
+        let mut timers = Timers::default();
+        let late = timers.insert(Duration::from_millis(30), None);
+        let early = timers.insert(Duration::from_millis(10), None);
+        let middle = timers.insert(Duration::from_millis(20), None);
+        let pending = timers.insert(Duration::from_secs(60), None);
+
+        let now = Instant::now() + Duration::from_secs(1);
+        assert_eq!(timers.take_due(now), [early, middle, late]);
+        assert_eq!(timers.take_due(now), []);
+
+        timers.remove(pending);
+        assert_eq!(timers.next_deadline(), None);
+    }
+
+    #[test]
+    fn removed_timers_are_not_due() {
+        let mut timers = Timers::default();
+        let timeout = timers.insert(Duration::ZERO, None);
+        let interval = timers.insert(Duration::ZERO, Some(Duration::from_millis(10)));
+        timers.remove(timeout);
+
+        let now = Instant::now() + Duration::from_millis(1);
+        assert_eq!(timers.take_due(now), [interval]);
+
+        timers.remove(interval);
+        assert_eq!(timers.take_due(now + Duration::from_secs(1)), []);
+    }
+
+    #[test]
+    fn zero_interval_is_clamped() {
+        let mut timers = Timers::default();
+        let interval = timers.insert(Duration::ZERO, Some(Duration::ZERO));
+
+        let now = Instant::now();
+        assert_eq!(timers.take_due(now), [interval]);
+        assert!(timers
+            .next_deadline()
+            .is_some_and(|deadline| deadline > now));
+    }
+}
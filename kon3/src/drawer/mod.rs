@@ -1,35 +1,169 @@
 pub mod resources;
 
+mod recording;
+
+use crate::Element;
+use crate::LocationRect;
+use recording::record;
+use recording::DrawCommand;
+use recording::Recording;
+use resources::Resources;
 use std::ptr;
 use std::sync::Arc;
-use sww::buffers::MutVecBuffer;
+use sww::buffers::InstanceArena;
 use sww::drawing::Mesh;
 use sww::drawing::MeshPipeline;
+use sww::drawing::SdfPipeline;
+use sww::drawing::StencilMode;
+use sww::shaders::mesh::BindGroup0;
+use sww::shaders::mesh::BindGroup1;
 use sww::shaders::mesh::BindGroups;
 use sww::shaders::mesh::Transform;
+use sww::shaders::sdf::SdfInstance;
+use sww::vec2;
 use sww::wgpu;
-use sww::window::RenderWindow;
+use sww::window::event::IntSize;
+use sww::window::Frame;
+use sww::window::RenderTarget;
+use sww::Color;
+use sww::Vec2;
+
+pub use recording::DrawRecording;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ActiveDrawer {
     Mesh,
+    Sdf,
 }
 
 #[derive(Default)]
 pub struct Drawers {
     active: Option<ActiveDrawer>,
     mesh: Option<MeshDrawerInfo>,
+    sdf: Option<SdfDrawerInfo>,
+    stencil_reference: u32,
+    /// Clips of the enclosing [`DrawPass::clipped`] calls, each within the previous one.
+    scissors: Vec<ScissorRect>,
+    recordings: Vec<Recording>,
+    canvas: Option<Canvas>,
+    stats: DrawStats,
+}
+
+impl Drawers {
+    /// Prepares for drawing a new frame, after the previous one has been submitted.
+    pub fn start_frame(&mut self) {
+        self.stats = DrawStats::default();
+
+        if let Some(mesh) = &mut self.mesh {
+            mesh.arena.clear();
+        }
+        if let Some(sdf) = &mut self.sdf {
+            sdf.arena.clear();
+        }
+    }
+
+    /// Work done while drawing the last frame.
+    pub fn stats(&self) -> DrawStats {
+        self.stats
+    }
+}
+
+/// Counts of the work recorded into a render pass, to check how well draws are batched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DrawStats {
+    /// Runs of consecutive mesh draws sharing a mesh and bind groups.
+    pub batches: usize,
+    /// Draw calls of all drawers.
+    pub draw_calls: usize,
+    pub instances: usize,
+}
+
+/// Holds the frame between redraws, so that only dirty parts of it need to be drawn again.
+struct Canvas {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl Canvas {
+    fn new(rw: &dyn RenderTarget) -> Self {
+        let size = rw.size();
+        let texture = rw.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("Canvas"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: rw.swapchain_format(),
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&Default::default());
+
+        Self { texture, view }
+    }
+
+    fn matches(&self, rw: &dyn RenderTarget) -> bool {
+        let size = rw.size();
+        let texture_size = self.texture.size();
+        (texture_size.width, texture_size.height) == (size.width.max(1), size.height.max(1))
+            && self.texture.format() == rw.swapchain_format()
+    }
+}
+
+/// Clip rect in whole pixels of the render target.
+#[derive(Clone, Copy)]
+struct ScissorRect {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl ScissorRect {
+    fn full(size: IntSize) -> Self {
+        Self {
+            min: Vec2::ZERO,
+            max: vec2(size.width as _, size.height as _),
+        }
+    }
+
+    fn new(location: LocationRect) -> Self {
+        let rect = location.rect();
+        let window_size = location.window_size();
+        let window_size = vec2(window_size.width as _, window_size.height as _);
+        let to_pixels = |point: Vec2| (vec2(point.x, -point.y) + 1.) / 2. * window_size;
+
+        // partially covered pixels are included
+        let (a, b) = (
+            to_pixels(rect.top_left),
+            to_pixels(rect.top_left + rect.size),
+        );
+        Self {
+            min: a.min(b).floor(),
+            max: a.max(b).ceil(),
+        }
+    }
+
+    fn intersect(self, other: Self) -> Self {
+        let min = self.min.max(other.min);
+        Self {
+            min,
+            max: self.max.min(other.max).max(min),
+        }
+    }
 }
 
 pub struct DrawPass<'s, 'e> {
-    rw: Arc<RenderWindow>,
+    rw: Arc<dyn RenderTarget>,
     render_pass: &'s mut wgpu::RenderPass<'e>,
     drawers: &'s mut Drawers,
 }
 
 impl<'s, 'e> DrawPass<'s, 'e> {
     pub fn new(
-        rw: Arc<RenderWindow>,
+        rw: Arc<dyn RenderTarget>,
         render_pass: &'s mut wgpu::RenderPass<'e>,
         drawers: &'s mut Drawers,
     ) -> Self {
@@ -41,6 +175,89 @@ impl<'s, 'e> DrawPass<'s, 'e> {
     }
 }
 
+/// Clears the frame and draws `element` over all of it.
+pub fn draw_frame(
+    rw: Arc<dyn RenderTarget>,
+    frame: &mut Frame,
+    drawers: &mut Drawers,
+    resources: &Resources,
+    element: &(impl Element + ?Sized),
+) {
+    draw_frame_dirty(rw, frame, drawers, resources, element, None);
+}
+
+/// Draws `element` again only within `dirty_rects`, keeping the rest of the previous frame, or
+/// over all of the frame if there are none or the previous frame can't be reused.
+///
+/// Once there are dirty rects, frames are drawn into a retained canvas and copied to the surface.
+/// Until then, or if the surface lacks [`wgpu::TextureUsages::COPY_DST`], they're drawn to the
+/// surface directly, and all of it is redrawn.
+pub fn draw_frame_dirty(
+    rw: Arc<dyn RenderTarget>,
+    frame: &mut Frame,
+    drawers: &mut Drawers,
+    resources: &Resources,
+    element: &(impl Element + ?Sized),
+    dirty_rects: Option<&[LocationRect]>,
+) {
+    drawers.start_frame();
+
+    let (commands, surface) = frame.commands_surface();
+    let can_copy = (surface.texture().usage()).contains(wgpu::TextureUsages::COPY_DST);
+    let dirty_rects = dirty_rects.filter(|_| can_copy);
+
+    let canvas = (drawers.canvas.take()).filter(|canvas| can_copy && canvas.matches(&*rw));
+    let (canvas, dirty_rects) = match (canvas, dirty_rects) {
+        (Some(canvas), dirty_rects) => (Some(canvas), dirty_rects),
+        // the first frame with dirty rects is drawn fully, so that later ones can reuse it
+        (None, Some(rects)) if !rects.is_empty() => (Some(Canvas::new(&*rw)), None),
+        (None, _) => (None, None),
+    };
+
+    {
+        let load = match dirty_rects {
+            Some(_) => wgpu::LoadOp::Load,
+            None => wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+        };
+        let color_attachment = match &canvas {
+            Some(canvas) => surface.color_attachment_to(&canvas.view, load),
+            None => surface.color_attachment(load),
+        };
+        let mut render_pass = (commands.encoder()).begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[Some(color_attachment)],
+            depth_stencil_attachment: surface.depth_stencil_attachment(),
+            ..Default::default()
+        });
+
+        let location = LocationRect::new(rw.size());
+        let size = location.rect().size / location.pixel_size();
+        let mut pass = DrawPass::new(rw, &mut render_pass, drawers);
+
+        match dirty_rects {
+            None => element.draw(&mut pass, resources, location),
+
+            Some(dirty_rects) => {
+                for &dirty_rect in dirty_rects {
+                    pass.clipped(dirty_rect, |pass| {
+                        let clear = SdfInstance::new(location.rect(), size, Color::BLACK);
+                        pass.sdf().draw(clear);
+                        element.draw(pass, resources, location);
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(canvas) = &canvas {
+        (commands.encoder()).copy_texture_to_texture(
+            canvas.texture.as_image_copy(),
+            surface.texture().as_image_copy(),
+            canvas.texture.size(),
+        );
+    }
+    drawers.canvas = canvas;
+}
+
 impl<'e> DrawPass<'_, 'e> {
     pub fn mesh(&mut self) -> MeshDrawer<'_, 'e> {
         self.set_active(ActiveDrawer::Mesh);
@@ -50,9 +267,134 @@ impl<'e> DrawPass<'_, 'e> {
         MeshDrawer {
             render_pass: self.render_pass,
             info,
+            stats: &mut self.drawers.stats,
+            recordings: &mut self.drawers.recordings,
         }
     }
 
+    pub fn sdf(&mut self) -> SdfDrawer<'_, 'e> {
+        self.set_active(ActiveDrawer::Sdf);
+        let info =
+            (self.drawers.sdf).get_or_insert_with(|| SdfDrawerInfo::new(Arc::clone(&self.rw)));
+
+        SdfDrawer {
+            render_pass: self.render_pass,
+            info,
+            stats: &mut self.drawers.stats,
+            recordings: &mut self.drawers.recordings,
+        }
+    }
+
+    /// Draws with `f` and returns what it drew, so that it can be replayed later.
+    pub fn record(&mut self, f: impl FnOnce(&mut DrawPass<'_, 'e>)) -> DrawRecording {
+        let stencil_base = self.drawers.stencil_reference;
+        self.drawers.recordings.push(Recording::new(stencil_base));
+        f(self);
+        self.drawers.recordings.pop().unwrap().recording
+    }
+
+    /// Draws `recording` again, within the current clip and mask.
+    pub fn replay(&mut self, recording: &DrawRecording) {
+        let stencil_base = self.drawers.stencil_reference;
+
+        for command in recording.commands() {
+            match *command {
+                DrawCommand::Mesh(ref mesh_info, transform) => {
+                    self.mesh().draw(mesh_info, transform);
+                }
+                DrawCommand::Sdf(instance) => self.sdf().draw(instance),
+                DrawCommand::PushClip(scissor) => self.push_clip(scissor),
+                DrawCommand::PopClip => self.pop_clip(),
+                DrawCommand::Stencil(stencil_mode, reference) => {
+                    self.set_stencil(stencil_mode, stencil_base + reference);
+                }
+            }
+        }
+    }
+
+    /// Draws `contents` clipped to the meshes drawn by `mask`, which is called once to add the
+    /// mask and once to remove it. Masks nest. Without a stencil buffer on the render target,
+    /// `contents` is drawn unclipped.
+    pub fn masked(
+        &mut self,
+        mask: impl Fn(&mut MeshDrawer),
+        contents: impl FnOnce(&mut DrawPass<'_, 'e>),
+    ) {
+        let reference = self.drawers.stencil_reference;
+
+        self.set_stencil(StencilMode::Increment, reference);
+        mask(&mut self.mesh());
+        self.set_stencil(StencilMode::Test, reference + 1);
+
+        contents(self);
+
+        self.set_stencil(StencilMode::Decrement, reference + 1);
+        mask(&mut self.mesh());
+        self.set_stencil(StencilMode::Test, reference);
+    }
+
+    /// Draws `contents` clipped to the pixels of `location`, within the clip of any enclosing
+    /// call.
+    pub fn clipped(
+        &mut self,
+        location: LocationRect,
+        contents: impl FnOnce(&mut DrawPass<'_, 'e>),
+    ) {
+        self.push_clip(ScissorRect::new(location));
+        contents(self);
+        self.pop_clip();
+    }
+
+    fn push_clip(&mut self, scissor: ScissorRect) {
+        record(&mut self.drawers.recordings, |_| {
+            DrawCommand::PushClip(scissor)
+        });
+
+        let full = ScissorRect::full(self.rw.size());
+        let parent = self.drawers.scissors.last().copied().unwrap_or(full);
+        self.drawers.scissors.push(scissor.intersect(parent));
+        self.apply_scissor();
+    }
+
+    fn pop_clip(&mut self) {
+        record(&mut self.drawers.recordings, |_| DrawCommand::PopClip);
+
+        self.drawers.scissors.pop();
+        self.apply_scissor();
+    }
+
+    fn apply_scissor(&mut self) {
+        self.end_batch();
+
+        let full = ScissorRect::full(self.rw.size());
+        let scissor = self.drawers.scissors.last().copied().unwrap_or(full);
+        let ScissorRect { min, max } = scissor.intersect(full);
+        let size = max - min;
+        (self.render_pass).set_scissor_rect(min.x as _, min.y as _, size.x as _, size.y as _);
+    }
+
+    fn set_stencil(&mut self, stencil_mode: StencilMode, reference: u32) {
+        record(&mut self.drawers.recordings, |recording| {
+            DrawCommand::Stencil(
+                stencil_mode,
+                reference.saturating_sub(recording.stencil_base),
+            )
+        });
+        self.end_batch();
+
+        self.drawers.stencil_reference = reference;
+        self.render_pass.set_stencil_reference(reference);
+        (self.drawers.mesh)
+            .get_or_insert_with(|| MeshDrawerInfo::new(Arc::clone(&self.rw)))
+            .stencil_mode = stencil_mode;
+    }
+
+    /// Draws everything batched so far, so that render pass state can be changed.
+    fn end_batch(&mut self) {
+        self.flush();
+        self.drawers.active = None;
+    }
+
     fn set_active(&mut self, active: ActiveDrawer) {
         if (self.drawers.active).is_some_and(|self_active| self_active != active) {
             self.flush();
@@ -68,6 +410,19 @@ impl<'e> DrawPass<'_, 'e> {
                     MeshDrawer {
                         render_pass: self.render_pass,
                         info,
+                        stats: &mut self.drawers.stats,
+                        recordings: &mut self.drawers.recordings,
+                    }
+                    .flush();
+                }
+
+                ActiveDrawer::Sdf => {
+                    let info = self.drawers.sdf.as_mut().unwrap();
+                    SdfDrawer {
+                        render_pass: self.render_pass,
+                        info,
+                        stats: &mut self.drawers.stats,
+                        recordings: &mut self.drawers.recordings,
                     }
                     .flush();
                 }
@@ -79,66 +434,167 @@ impl<'e> DrawPass<'_, 'e> {
 impl Drop for DrawPass<'_, '_> {
     fn drop(&mut self) {
         self.flush();
+
+        if let Some(mesh) = &mut self.drawers.mesh {
+            mesh.arena.upload();
+        }
+        if let Some(sdf) = &mut self.drawers.sdf {
+            sdf.arena.upload();
+        }
     }
 }
 
 #[derive(Clone)]
 pub struct MeshDrawingInfo {
     pub mesh: &'static Mesh,
-    pub bind_groups: BindGroups<'static>,
+    pub bind_group0: &'static BindGroup0,
+    /// Shared, so that a texture is dropped once nothing draws it anymore.
+    pub bind_group1: Arc<BindGroup1>,
 }
 
+/// Draws are batched while the mesh and both bind groups stay the same objects.
 impl PartialEq for MeshDrawingInfo {
     fn eq(&self, other: &Self) -> bool {
-        ptr::eq(&self.mesh, &other.mesh)
+        ptr::eq(self.mesh, other.mesh)
+            && ptr::eq(self.bind_group0, other.bind_group0)
+            && Arc::ptr_eq(&self.bind_group1, &other.bind_group1)
     }
 }
 
 impl Eq for MeshDrawingInfo {}
 
 pub struct MeshDrawerInfo {
-    transforms: MutVecBuffer<Transform>,
+    /// Transforms of the current batch.
+    transforms: Vec<Transform>,
+    arena: InstanceArena<Transform>,
     pipeline: MeshPipeline,
+    increment_pipeline: MeshPipeline,
+    decrement_pipeline: MeshPipeline,
+    stencil_mode: StencilMode,
     current_mesh_info: Option<MeshDrawingInfo>,
 }
 
 impl MeshDrawerInfo {
-    pub fn new(rw: Arc<RenderWindow>) -> Self {
-        let pipeline = MeshPipeline::new(&rw);
-        let transforms = MutVecBuffer::default_vertex(rw);
+    pub fn new(rw: Arc<dyn RenderTarget>) -> Self {
+        let pipeline = MeshPipeline::new(&*rw);
+        let increment_pipeline = MeshPipeline::new_stencil(&*rw, StencilMode::Increment);
+        let decrement_pipeline = MeshPipeline::new_stencil(&*rw, StencilMode::Decrement);
+        let arena = InstanceArena::new(rw);
 
         Self {
-            transforms,
+            transforms: Vec::new(),
+            arena,
             pipeline,
+            increment_pipeline,
+            decrement_pipeline,
+            stencil_mode: StencilMode::Test,
             current_mesh_info: None,
         }
     }
+
+    fn pipeline(&self) -> &MeshPipeline {
+        match self.stencil_mode {
+            StencilMode::Test => &self.pipeline,
+            StencilMode::Increment => &self.increment_pipeline,
+            StencilMode::Decrement => &self.decrement_pipeline,
+        }
+    }
 }
 
+/// Batches consecutive draws of the same mesh with the same bind groups into one instanced draw
+/// call, keeping the order of all draws.
 pub struct MeshDrawer<'s, 'e> {
     render_pass: &'s mut wgpu::RenderPass<'e>,
     info: &'s mut MeshDrawerInfo,
+    stats: &'s mut DrawStats,
+    recordings: &'s mut Vec<Recording>,
 }
 
 impl MeshDrawer<'_, '_> {
     pub fn draw(&mut self, mesh_info: &MeshDrawingInfo, transform: Transform) {
-        if self.info.current_mesh_info.as_ref() == Some(mesh_info) {
+        record(self.recordings, |_| {
+            DrawCommand::Mesh(mesh_info.clone(), transform)
+        });
+
+        if (self.info.current_mesh_info.as_ref()).is_some_and(|current| current != mesh_info) {
             self.flush();
         }
 
-        self.info.current_mesh_info = Some(mesh_info.clone());
+        if self.info.current_mesh_info.is_none() {
+            self.info.current_mesh_info = Some(mesh_info.clone());
+        }
         self.info.transforms.push(transform);
     }
 
     fn flush(&mut self) {
-        if let Some(MeshDrawingInfo { mesh, bind_groups }) = self.info.current_mesh_info {
-            mesh.draw(
+        if let Some(MeshDrawingInfo {
+            mesh,
+            bind_group0,
+            bind_group1,
+        }) = self.info.current_mesh_info.take()
+        {
+            let transforms = self.info.arena.push(&self.info.transforms);
+            mesh.draw_instances(
                 self.render_pass,
-                &self.info.pipeline,
-                bind_groups,
-                &self.info.transforms,
+                self.info.pipeline(),
+                BindGroups {
+                    bind_group0,
+                    bind_group1: &bind_group1,
+                },
+                &transforms,
             );
+
+            self.stats.batches += 1;
+            self.stats.draw_calls += 1;
+            self.stats.instances += self.info.transforms.len();
             self.info.transforms.clear();
         }
     }
 }
+
+pub struct SdfDrawerInfo {
+    /// Instances of the current batch.
+    instances: Vec<SdfInstance>,
+    arena: InstanceArena<SdfInstance>,
+    pipeline: SdfPipeline,
+}
+
+impl SdfDrawerInfo {
+    pub fn new(rw: Arc<dyn RenderTarget>) -> Self {
+        let pipeline = SdfPipeline::new(&*rw);
+        let arena = InstanceArena::new(rw);
+
+        Self {
+            instances: Vec::new(),
+            arena,
+            pipeline,
+        }
+    }
+}
+
+/// Draws rounded boxes, borders and shadows. All of them share one pipeline, so they are drawn
+/// in a single batch until another drawer is used.
+pub struct SdfDrawer<'s, 'e> {
+    render_pass: &'s mut wgpu::RenderPass<'e>,
+    info: &'s mut SdfDrawerInfo,
+    stats: &'s mut DrawStats,
+    recordings: &'s mut Vec<Recording>,
+}
+
+impl SdfDrawer<'_, '_> {
+    pub fn draw(&mut self, instance: SdfInstance) {
+        record(self.recordings, |_| DrawCommand::Sdf(instance));
+        self.info.instances.push(instance);
+    }
+
+    fn flush(&mut self) {
+        if !self.info.instances.is_empty() {
+            let instances = self.info.arena.push(&self.info.instances);
+            (self.info.pipeline).draw(self.render_pass, &instances);
+
+            self.stats.draw_calls += 1;
+            self.stats.instances += self.info.instances.len();
+            self.info.instances.clear();
+        }
+    }
+}
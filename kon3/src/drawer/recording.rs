@@ -0,0 +1,50 @@
+use super::MeshDrawingInfo;
+use super::ScissorRect;
+use sww::drawing::StencilMode;
+use sww::shaders::mesh::Transform;
+use sww::shaders::sdf::SdfInstance;
+
+#[derive(Clone)]
+pub(super) enum DrawCommand {
+    Mesh(MeshDrawingInfo, Transform),
+    Sdf(SdfInstance),
+    PushClip(ScissorRect),
+    PopClip,
+    /// The reference is relative to the one the recording started with.
+    Stencil(StencilMode, u32),
+}
+
+/// Draw commands of a subtree, which can be replayed instead of drawing it again.
+#[derive(Clone, Default)]
+pub struct DrawRecording {
+    commands: Vec<DrawCommand>,
+}
+
+impl DrawRecording {
+    pub(super) fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+}
+
+pub(super) struct Recording {
+    pub recording: DrawRecording,
+    pub stencil_base: u32,
+}
+
+impl Recording {
+    pub fn new(stencil_base: u32) -> Self {
+        Self {
+            recording: DrawRecording::default(),
+            stencil_base,
+        }
+    }
+}
+
+/// Adds `command` to every recording in progress, since enclosing recordings contain the draws of
+/// nested ones as well.
+pub(super) fn record(recordings: &mut [Recording], command: impl Fn(&Recording) -> DrawCommand) {
+    for recording in recordings {
+        let command = command(recording);
+        recording.recording.commands.push(command);
+    }
+}
@@ -1,23 +1,25 @@
 use crate::resources::Resource;
 use std::ops::Deref;
+use std::sync::Arc;
 use sww::buffers::Binding;
 use sww::buffers::MutBuffer;
 use sww::drawing::Mesh;
 use sww::media::make_default_texture;
 use sww::media::DefaultView;
+use sww::media::Sampling;
 use sww::shaders::mesh::BindGroup0;
 use sww::shaders::mesh::BindGroup1;
 use sww::shaders::mesh::BindGroupLayout0;
 use sww::shaders::mesh::BindGroupLayout1;
 use sww::shaders::mesh::Transform;
-use sww::window::RenderWindow;
+use sww::window::RenderTarget;
 use sww::Vec2;
 
 /// A mesh of a unit square with its top left corner being positioned at `(0, 0)`.
 pub struct UnitSquareTopLeft(Mesh);
 
 impl Resource for UnitSquareTopLeft {
-    fn new(rw: &RenderWindow) -> Self {
+    fn new(rw: &dyn RenderTarget) -> Self {
         Self(Mesh::rect(rw, Vec2::ONE))
     }
 }
@@ -34,7 +36,7 @@ impl Deref for UnitSquareTopLeft {
 pub struct NoGlobalTransform(BindGroup0);
 
 impl Resource for NoGlobalTransform {
-    fn new(rw: &RenderWindow) -> Self {
+    fn new(rw: &dyn RenderTarget) -> Self {
         Self(BindGroup0::from_bindings(
             rw.device(),
             BindGroupLayout0 {
@@ -55,23 +57,24 @@ impl Deref for NoGlobalTransform {
 }
 
 /// A bind group 1 with no texture (a white pixel).
-pub struct DefaultTexture(BindGroup1);
+pub struct DefaultTexture(Arc<BindGroup1>);
 
 impl Resource for DefaultTexture {
-    fn new(rw: &RenderWindow) -> Self {
+    fn new(rw: &dyn RenderTarget) -> Self {
         let default_texture = make_default_texture(rw.device(), rw.queue());
 
-        Self(BindGroup1::from_bindings(
+        Self(Arc::new(BindGroup1::from_bindings(
             rw.device(),
             BindGroupLayout1 {
                 texture: &default_texture.default_view(),
+                texture_sampler: &Sampling::NEAREST.make_sampler(rw.device()),
             },
-        ))
+        )))
     }
 }
 
 impl Deref for DefaultTexture {
-    type Target = BindGroup1;
+    type Target = Arc<BindGroup1>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
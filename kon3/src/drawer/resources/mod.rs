@@ -1,30 +1,44 @@
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 use parking_lot::RwLockUpgradableReadGuard;
 use std::any::Any;
 use std::any::TypeId;
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::sync::Arc;
-use sww::window::RenderWindow;
+use std::sync::Weak;
+use sww::window::RenderTarget;
 
 pub mod mesh;
 
 pub trait Resource: 'static {
-    fn new(rw: &RenderWindow) -> Self;
+    fn new(rw: &dyn RenderTarget) -> Self;
 }
 
 pub struct Resources {
-    rw: Arc<RenderWindow>,
+    rw: Arc<dyn RenderTarget>,
     resources: RwLock<HashMap<TypeId, &'static dyn Any>>,
+    owned_resources: Mutex<HashMap<(TypeId, TypeId), Box<dyn Any>>>,
+}
+
+struct OwnedResource<T> {
+    owner: Weak<dyn Any + Send + Sync>,
+    value: Arc<T>,
 }
 
 impl Resources {
-    pub fn new(rw: Arc<RenderWindow>) -> Self {
+    pub fn new(rw: Arc<dyn RenderTarget>) -> Self {
         Self {
             rw,
             resources: Default::default(),
+            owned_resources: Default::default(),
         }
     }
 
+    pub fn rw(&self) -> &dyn RenderTarget {
+        &*self.rw
+    }
+
     pub fn get<T: Resource>(&self) -> &'static T {
         let guard = self.resources.upgradable_read();
         let key = TypeId::of::<T>();
@@ -33,10 +47,46 @@ impl Resources {
             res
         } else {
             let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
-            guard.insert(key, Box::<dyn Any>::leak(Box::new(T::new(&self.rw))));
+            guard.insert(key, Box::<dyn Any>::leak(Box::new(T::new(&*self.rw))));
             guard[&key]
         }
         .downcast_ref()
         .unwrap()
     }
+
+    /// Like [`Self::get`], but a separate value is created by `new` for each `key`, and kept only
+    /// while `owner` is alive.
+    ///
+    /// Values of dropped owners are dropped when another value of the same types is created.
+    pub fn get_owned<K: Hash + Eq + 'static, T: 'static>(
+        &self,
+        key: K,
+        owner: &Arc<impl Any + Send + Sync>,
+        new: impl FnOnce(&dyn RenderTarget) -> T,
+    ) -> Arc<T> {
+        let mut owned_resources = self.owned_resources.lock();
+        let resources = owned_resources
+            .entry((TypeId::of::<K>(), TypeId::of::<T>()))
+            .or_insert_with(|| Box::new(HashMap::<K, OwnedResource<T>>::new()))
+            .downcast_mut::<HashMap<K, OwnedResource<T>>>()
+            .unwrap();
+
+        if let Some(resource) = resources.get(&key) {
+            if resource.owner.strong_count() != 0 {
+                return Arc::clone(&resource.value);
+            }
+        }
+
+        resources.retain(|_, resource| resource.owner.strong_count() != 0);
+        let owner = Arc::downgrade(owner);
+        let value = Arc::new(new(&*self.rw));
+        resources.insert(
+            key,
+            OwnedResource {
+                owner,
+                value: Arc::clone(&value),
+            },
+        );
+        value
+    }
 }
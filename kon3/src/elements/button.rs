@@ -0,0 +1,65 @@
+use super::interaction::Interaction;
+use super::rounded_rect;
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::elements::SplitSize;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::IntoEventResult;
+use crate::LocationRect;
+
+const RADIUS: f32 = 4.;
+
+pub struct Button<E, F> {
+    element: E,
+    f: F,
+    interaction: Interaction,
+}
+
+impl<E: Element, F: Fn(&Signaler) -> U, U: IntoEventResult> Element for Button<E, F> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        rounded_rect(self.interaction.color())
+            .radius(RADIUS)
+            .draw(pass, resources, location);
+        self.element.draw(pass, resources, location);
+    }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent, F: Fn(&Signaler) -> U, U: IntoEventResult> HandleEvent for Button<E, F> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if self.interaction.handle_event(signaler, event, location) {
+            (self.f)(signaler).into_event_result()?;
+        }
+
+        self.element.handle_event(signaler, event, location)
+    }
+}
+
+/// Draws `element` over a background reacting to hover and press, and calls `f` when clicked.
+pub fn button<E, F: Fn(&Signaler) -> U, U: IntoEventResult>(
+    ra_fixture_element: E,
+    ra_fixture_f: F,
+) -> Button<E, F> {
+    Button {
+        element: ra_fixture_element,
+        f: ra_fixture_f,
+        interaction: Interaction::default(),
+    }
+}
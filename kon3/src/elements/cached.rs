@@ -0,0 +1,66 @@
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::drawer::DrawRecording;
+use crate::elements::SplitSize;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use parking_lot::Mutex;
+
+/// Replays what `element` drew until it requests a redraw, and limits the redraws it requests to
+/// its location. Anything it draws outside of its location may be left stale.
+pub struct Cached<E> {
+    element: E,
+    recording: Mutex<Option<(LocationRect, DrawRecording)>>,
+}
+
+impl<E: Element> Element for Cached<E> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let mut cache = self.recording.lock();
+        match &*cache {
+            Some((cached_location, recording)) if *cached_location == location => {
+                pass.replay(recording);
+            }
+            _ => {
+                let recording = pass.record(|pass| self.element.draw(pass, resources, location));
+                *cache = Some((location, recording));
+            }
+        }
+    }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent> HandleEvent for Cached<E> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        let (result, redraw) = signaler.redraw_scope(location, || {
+            self.element.handle_event(signaler, event, location)
+        });
+        if redraw {
+            *self.recording.lock() = None;
+        }
+        result
+    }
+}
+
+pub const fn cached<E: Element>(ra_fixture_element: E) -> Cached<E> {
+    Cached {
+        element: ra_fixture_element,
+        recording: Mutex::new(None),
+    }
+}
@@ -0,0 +1,127 @@
+use super::interaction::Interaction;
+use super::interaction::ACCENT_COLOR;
+use super::interaction::NORMAL_COLOR;
+use super::rounded_rect;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrowMut;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use parking_lot::Mutex;
+use std::borrow::Borrow;
+use std::borrow::BorrowMut;
+use sww::vec2;
+use sww::Vec2;
+
+#[derive(Clone, Copy)]
+enum CheckboxStyle {
+    Checkbox,
+    Toggle,
+}
+
+/// A `bool` switch drawn as a [`checkbox`] or a [`toggle`].
+pub struct Checkbox<Src> {
+    source: Mutex<Src>,
+    style: CheckboxStyle,
+    interaction: Interaction,
+}
+
+impl<Src: ValueSourceBorrowMut<bool>> Element for Checkbox<Src> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let checked = *(*self.source.lock().value()).borrow();
+
+        let size = location.window_rect_size();
+        let side = (size.width.min(size.height) as f32 * 0.6).round();
+        let padding = ((size.height as f32 - side) / 2.).round();
+        let color = self.interaction.color();
+
+        match self.style {
+            CheckboxStyle::Checkbox => {
+                let top_left = Vec2::splat(padding);
+                rounded_rect(color).radius((side / 5.).round()).draw(
+                    pass,
+                    resources,
+                    location.pixel_subrect(top_left, Vec2::splat(side)),
+                );
+
+                if checked {
+                    let inset = (side / 5.).round();
+                    rounded_rect(ACCENT_COLOR).radius(inset / 2.).draw(
+                        pass,
+                        resources,
+                        location.pixel_subrect(top_left + inset, Vec2::splat(side - inset * 2.)),
+                    );
+                }
+            }
+
+            CheckboxStyle::Toggle => {
+                let top_left = Vec2::splat(padding);
+                let track_color = if checked { ACCENT_COLOR } else { NORMAL_COLOR };
+                rounded_rect(track_color).radius(side / 2.).draw(
+                    pass,
+                    resources,
+                    location.pixel_subrect(top_left, vec2(side * 2., side)),
+                );
+
+                let inset = (side / 8.).round();
+                let knob_x = if checked { side } else { 0. };
+                rounded_rect(self.interaction.knob_color())
+                    .radius(side / 2. - inset)
+                    .draw(
+                        pass,
+                        resources,
+                        location.pixel_subrect(
+                            top_left + vec2(knob_x, 0.) + inset,
+                            Vec2::splat(side - inset * 2.),
+                        ),
+                    );
+            }
+        }
+    }
+}
+
+impl<Src: ValueSourceBorrowMut<bool>> HandleEvent for Checkbox<Src> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if self.interaction.handle_event(signaler, event, location) {
+            let mut source = self.source.lock();
+            let mut value = source.value_mut(signaler);
+            let value: &mut bool = (*value).borrow_mut();
+            *value = !*value;
+            signaler.send(Signal::Redraw);
+        }
+
+        if let Event::SharedUpdated(addr) = *event {
+            if self.source.lock().contains_shared(addr) {
+                signaler.send(Signal::Redraw);
+            }
+        }
+
+        self.source.lock().handle_event(signaler, event, location)
+    }
+}
+
+pub fn checkbox<Src: ValueSourceBorrowMut<bool>>(ra_fixture_source: Src) -> Checkbox<Src> {
+    Checkbox {
+        source: Mutex::new(ra_fixture_source),
+        style: CheckboxStyle::Checkbox,
+        interaction: Interaction::default(),
+    }
+}
+
+pub fn toggle<Src: ValueSourceBorrowMut<bool>>(ra_fixture_source: Src) -> Checkbox<Src> {
+    Checkbox {
+        source: Mutex::new(ra_fixture_source),
+        style: CheckboxStyle::Toggle,
+        interaction: Interaction::default(),
+    }
+}
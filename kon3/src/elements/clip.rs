@@ -0,0 +1,48 @@
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::elements::SplitSize;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+
+/// Keeps `element` from drawing outside of its location.
+pub struct Clip<E> {
+    element: E,
+}
+
+impl<E: Element> Element for Clip<E> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        pass.clipped(location, |pass| {
+            self.element.draw(pass, resources, location);
+        });
+    }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent> HandleEvent for Clip<E> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        self.element.handle_event(signaler, event, location)
+    }
+}
+
+pub const fn clip<E: Element>(ra_fixture_element: E) -> Clip<E> {
+    Clip {
+        element: ra_fixture_element,
+    }
+}
@@ -0,0 +1,70 @@
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::elements::SplitSize;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+
+/// Takes focus when pressed and takes part in Tab traversal.
+pub struct Focusable<E> {
+    element: E,
+    id: FocusId,
+}
+
+impl<E> Focusable<E> {
+    pub const fn id(&self) -> FocusId {
+        self.id
+    }
+
+    pub(crate) fn map<F>(self, f: impl FnOnce(E) -> F) -> Focusable<F> {
+        Focusable {
+            element: f(self.element),
+            id: self.id,
+        }
+    }
+}
+
+impl<E: Element> Element for Focusable<E> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        self.element.draw(pass, resources, location);
+    }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        order.push(self.id);
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent> HandleEvent for Focusable<E> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if let Event::PointerDown { point, .. } = *event {
+            if location.contains(point) {
+                signaler.set_focus(Some(self.id));
+            } else if signaler.is_focused(self.id) {
+                signaler.set_focus(None);
+            }
+        }
+
+        self.element.handle_event(signaler, event, location)
+    }
+}
+
+pub fn focusable<E>(ra_fixture_element: E) -> Focusable<E> {
+    Focusable {
+        element: ra_fixture_element,
+        id: FocusId::new(),
+    }
+}
@@ -1,3 +1,7 @@
+mod focusable;
 mod on_click;
+mod on_key;
 
+pub use focusable::*;
 pub use on_click::*;
+pub use on_key::*;
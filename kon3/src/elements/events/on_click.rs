@@ -1,6 +1,8 @@
+use crate::app::FocusId;
 use crate::app::Signaler;
 use crate::drawer::resources::Resources;
 use crate::drawer::DrawPass;
+use crate::elements::SplitSize;
 use crate::Element;
 use crate::Event;
 use crate::EventResult;
@@ -18,18 +20,30 @@ impl<E: Element, F: Fn(&Signaler) -> U, U: IntoEventResult> Element for OnClick<
     fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
         self.element.draw(pass, resources, location);
     }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
 }
 
 impl<E: HandleEvent, F: Fn(&Signaler) -> U, U: IntoEventResult> HandleEvent for OnClick<E, F> {
-    fn handle_event(&self, signaler: &crate::prelude::Signaler, event: &Event) -> EventResult {
-        if let Event::Click { point: _, button } = *event {
-            // TODO && location.contains(point)
-            if button == MouseButton::Left {
+    fn handle_event(
+        &self,
+        signaler: &crate::prelude::Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if let Event::Click { point, button } = *event {
+            if button == MouseButton::Left && location.contains(point) {
                 (self.f)(signaler).into_event_result()?;
             }
         }
 
-        self.element.handle_event(signaler, event)
+        self.element.handle_event(signaler, event, location)
     }
 }
 
@@ -0,0 +1,70 @@
+use super::focusable;
+use super::Focusable;
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::elements::SplitSize;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::IntoEventResult;
+use crate::LocationRect;
+use sww::window::event::KeyEvent;
+use sww::window::event::KeyboardModifiers;
+
+pub struct OnKey<E, F> {
+    element: Focusable<E>,
+    f: F,
+}
+
+impl<E: Element, F: Fn(&Signaler, &KeyEvent, KeyboardModifiers) -> U, U: IntoEventResult> Element
+    for OnKey<E, F>
+{
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        self.element.draw(pass, resources, location);
+    }
+
+    fn split_size(&self) -> SplitSize {
+        self.element.split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent, F: Fn(&Signaler, &KeyEvent, KeyboardModifiers) -> U, U: IntoEventResult>
+    HandleEvent for OnKey<E, F>
+{
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if let Event::Key {
+            event: ref key_event,
+            modifiers,
+        } = *event
+        {
+            if signaler.is_focused(self.element.id()) {
+                (self.f)(signaler, key_event, modifiers).into_event_result()?;
+            }
+        }
+
+        self.element.handle_event(signaler, event, location)
+    }
+}
+
+/// Makes `element` [`focusable`] and calls `f` on key events while it is focused.
+pub fn on_key<E, F: Fn(&Signaler, &KeyEvent, KeyboardModifiers) -> U, U: IntoEventResult>(
+    ra_fixture_element: E,
+    ra_fixture_f: F,
+) -> OnKey<E, F> {
+    OnKey {
+        element: focusable(ra_fixture_element),
+        f: ra_fixture_f,
+    }
+}
@@ -0,0 +1,257 @@
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::resources::mesh::NoGlobalTransform;
+use crate::resources::mesh::UnitSquareTopLeft;
+use crate::values::AutoValueSource;
+use crate::values::ValueSourceBorrow;
+use crate::ContainsShared;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use crate::MeshDrawingInfo;
+use std::borrow::Borrow;
+use std::io::BufRead;
+use std::io::Seek;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use sww::image::RgbaImage;
+use sww::media;
+use sww::media::DefaultView;
+use sww::media::Filter;
+use sww::media::Sampling;
+use sww::shaders::mesh::BindGroup1;
+use sww::shaders::mesh::BindGroupLayout1;
+use sww::shaders::mesh::Rectangle;
+use sww::shaders::mesh::Transform;
+use sww::vec2;
+use sww::Color;
+use sww::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageId(u64);
+
+/// An image shared between elements. Clones refer to the same texture.
+#[derive(Clone)]
+pub struct ImageData {
+    id: ImageId,
+    image: Arc<RgbaImage>,
+}
+
+impl ImageData {
+    pub fn new(image: RgbaImage) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: ImageId(NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            image: Arc::new(image),
+        }
+    }
+
+    pub fn read(reader: impl BufRead + Seek) -> media::Result<Self> {
+        media::read_image(reader).map(Self::new)
+    }
+
+    pub const fn id(&self) -> ImageId {
+        self.id
+    }
+
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    pub fn size(&self) -> Vec2 {
+        vec2(self.image.width() as _, self.image.height() as _)
+    }
+
+    /// The texture is kept until the last clone of this is dropped.
+    fn bind_group(&self, resources: &Resources, sampling: Sampling) -> Arc<BindGroup1> {
+        resources.get_owned((self.id, sampling), &self.image, |rw| {
+            // nearest sampling is for pixel art, which shouldn't be smoothed when downscaled either
+            let texture = match sampling.filter {
+                Filter::Linear => {
+                    media::make_mipmapped_texture(rw.device(), rw.queue(), &self.image)
+                }
+                Filter::Nearest => media::make_texture(rw.device(), rw.queue(), &self.image),
+            };
+
+            BindGroup1::from_bindings(
+                rw.device(),
+                BindGroupLayout1 {
+                    texture: &texture.default_view(),
+                    texture_sampler: &sampling.make_sampler(rw.device()),
+                },
+            )
+        })
+    }
+}
+
+impl AutoValueSource for ImageData {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Fit {
+    /// Fills the location, ignoring the aspect ratio.
+    Stretch,
+    /// Fits inside the location, keeping the aspect ratio.
+    #[default]
+    Contain,
+    /// Fills the location, keeping the aspect ratio and cropping the rest.
+    Cover,
+    /// Keeps the original size, cropping what does not fit.
+    Center,
+}
+
+impl Fit {
+    /// Returns the size in pixels and the visible part of the image, which are centered.
+    fn apply(self, image_size: Vec2, available: Vec2) -> (Vec2, Vec2) {
+        match self {
+            Self::Stretch => (available, Vec2::ONE),
+
+            Self::Contain => {
+                let scale = (available / image_size).min_element();
+                (image_size * scale, Vec2::ONE)
+            }
+
+            Self::Cover => {
+                let scale = (available / image_size).max_element();
+                (available, available / (image_size * scale))
+            }
+
+            Self::Center => {
+                let size = image_size.min(available);
+                (size, size / image_size)
+            }
+        }
+    }
+}
+
+pub struct Image<Src, Clr = Color> {
+    source: Src,
+    fit: Fit,
+    sampling: Sampling,
+    tint: Clr,
+}
+
+impl<Src, Clr> Image<Src, Clr> {
+    pub fn fit(self, fit: Fit) -> Self {
+        Self { fit, ..self }
+    }
+
+    /// Use [`Sampling::NEAREST`] for pixel art.
+    pub fn sampling(self, sampling: Sampling) -> Self {
+        Self { sampling, ..self }
+    }
+
+    pub fn tint<C: ValueSourceBorrow<Color>>(self, ra_fixture_tint: C) -> Image<Src, C> {
+        Image {
+            source: self.source,
+            fit: self.fit,
+            sampling: self.sampling,
+            tint: ra_fixture_tint,
+        }
+    }
+}
+
+impl<Src: ValueSourceBorrow<ImageData>, Clr: ValueSourceBorrow<Color>> Element for Image<Src, Clr> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let source = self.source.value();
+        let image: &ImageData = (*source).borrow();
+
+        draw_texture(
+            pass,
+            resources,
+            location,
+            TextureDraw {
+                bind_group: image.bind_group(resources, self.sampling),
+                image_size: image.size(),
+                texture_rect: Rectangle::new(Vec2::ZERO, Vec2::ONE),
+                fit: self.fit,
+                tint: *(*self.tint.value()).borrow(),
+            },
+        );
+    }
+}
+
+/// A part of a texture to draw with [`draw_texture`].
+pub(super) struct TextureDraw {
+    pub bind_group: Arc<BindGroup1>,
+    /// Size of the drawn part in pixels.
+    pub image_size: Vec2,
+    /// The drawn part in `y`-down texture coordinates.
+    pub texture_rect: Rectangle,
+    pub fit: Fit,
+    pub tint: Color,
+}
+
+pub(super) fn draw_texture(
+    pass: &mut DrawPass,
+    resources: &Resources,
+    location: LocationRect,
+    texture: TextureDraw,
+) {
+    let TextureDraw {
+        bind_group,
+        image_size,
+        texture_rect,
+        fit,
+        tint,
+    } = texture;
+
+    let available = location.window_rect_size();
+    let available = vec2(available.width as _, available.height as _);
+    let (size, visible) = fit.apply(image_size, available);
+
+    let rect = location
+        .pixel_subrect(((available - size) / 2.).round(), size.round())
+        .rect();
+    // the shader samples textures bottom-up, while `y` points down here
+    let texture_rect = texture_rect
+        .subrect(Rectangle::new((Vec2::ONE - visible) / 2., visible))
+        .flip_vertically();
+
+    pass.mesh().draw(
+        &MeshDrawingInfo {
+            mesh: resources.get::<UnitSquareTopLeft>(),
+            bind_group0: resources.get::<NoGlobalTransform>(),
+            bind_group1: bind_group,
+        },
+        Transform::new_scale(rect.top_left, rect.size, tint, texture_rect),
+    );
+}
+
+impl<Src: ContainsShared + HandleEvent, Clr: ContainsShared + HandleEvent> HandleEvent
+    for Image<Src, Clr>
+{
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        match *event {
+            Event::SharedUpdated(addr)
+                if self.source.contains_shared(addr) || self.tint.contains_shared(addr) =>
+            {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.source.handle_event(signaler, event, location)?;
+        self.tint.handle_event(signaler, event, location)
+    }
+}
+
+pub fn image<Src: ValueSourceBorrow<ImageData>>(ra_fixture_source: Src) -> Image<Src> {
+    Image {
+        source: ra_fixture_source,
+        fit: Fit::default(),
+        sampling: Sampling::default(),
+        tint: Color::WHITE,
+    }
+}
@@ -0,0 +1,70 @@
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::Event;
+use crate::LocationRect;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use sww::window::event::MouseButton;
+use sww::Color;
+
+pub(crate) const NORMAL_COLOR: Color = Color::splat(0.25);
+pub(crate) const HOVERED_COLOR: Color = Color::splat(0.35);
+pub(crate) const PRESSED_COLOR: Color = Color::splat(0.18);
+pub(crate) const ACCENT_COLOR: Color = Color::new_rgb(0.2, 0.5, 0.9);
+
+/// Hover and press state of a widget.
+#[derive(Default)]
+pub(crate) struct Interaction {
+    hovered: AtomicBool,
+    pressed: AtomicBool,
+}
+
+impl Interaction {
+    /// Returns `true` if the widget was clicked: pressed and released within its location.
+    pub fn handle_event(&self, signaler: &Signaler, event: &Event, location: LocationRect) -> bool {
+        let left = MouseButton::Left;
+        match *event {
+            Event::PointerEnter { .. } => self.set(&self.hovered, true, signaler),
+            Event::PointerLeave { .. } => self.set(&self.hovered, false, signaler),
+
+            Event::PointerDown { point, button } if button == left && location.contains(point) => {
+                self.set(&self.pressed, true, signaler);
+            }
+
+            Event::PointerUp { point, button } if button == left && self.is_pressed() => {
+                self.set(&self.pressed, false, signaler);
+                return location.contains(point);
+            }
+
+            _ => {}
+        }
+
+        false
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.pressed.load(Ordering::Relaxed)
+    }
+
+    pub fn color(&self) -> Color {
+        if self.is_pressed() {
+            PRESSED_COLOR
+        } else if self.hovered.load(Ordering::Relaxed) {
+            HOVERED_COLOR
+        } else {
+            NORMAL_COLOR
+        }
+    }
+
+    /// Color of a knob or a handle, lighter than [`Self::color`].
+    pub fn knob_color(&self) -> Color {
+        let color = self.color();
+        Color::splat(color.r + 0.55)
+    }
+
+    fn set(&self, flag: &AtomicBool, value: bool, signaler: &Signaler) {
+        if flag.swap(value, Ordering::Relaxed) != value {
+            signaler.send(Signal::Redraw);
+        }
+    }
+}
@@ -2,6 +2,9 @@ use crate::app::Signal;
 use crate::app::Signaler;
 use crate::drawer::resources::Resources;
 use crate::drawer::DrawPass;
+use crate::text::Alignment;
+use crate::text::Font;
+use crate::text::TextLayout;
 use crate::values::ValueSourceBorrow;
 use crate::ContainsShared;
 use crate::Element;
@@ -10,55 +13,98 @@ use crate::EventResult;
 use crate::HandleEvent;
 use crate::LocationRect;
 use std::borrow::Borrow;
-use sww::shaders::mesh::Rectangle;
 use sww::vec2;
+use sww::Color;
 
-pub struct Label<Src> {
+const DEFAULT_SIZE: f32 = 16.;
+
+pub struct Label<Src, Clr = Color> {
     source: Src,
+    color: Clr,
+    font: Font,
+    size: f32,
+    horizontal: Alignment,
+    vertical: Alignment,
 }
 
-impl<Src: ValueSourceBorrow<str>> Element for Label<Src> {
-    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
-        // FIXME
-        use super::rect;
-        use sww::Color;
+impl<Src, Clr> Label<Src, Clr> {
+    pub fn color<C: ValueSourceBorrow<Color>>(self, ra_fixture_color: C) -> Label<Src, C> {
+        Label {
+            source: self.source,
+            color: ra_fixture_color,
+            font: self.font,
+            size: self.size,
+            horizontal: self.horizontal,
+            vertical: self.vertical,
+        }
+    }
 
-        let hash = {
-            use std::hash::DefaultHasher;
-            use std::hash::Hash;
-            use std::hash::Hasher;
+    pub fn font(self, font: Font) -> Self {
+        Self { font, ..self }
+    }
+
+    /// Font size in pixels.
+    pub fn size(self, size: f32) -> Self {
+        Self { size, ..self }
+    }
+
+    pub fn align(self, horizontal: Alignment, vertical: Alignment) -> Self {
+        Self {
+            horizontal,
+            vertical,
+            ..self
+        }
+    }
+}
+
+impl<Src: ValueSourceBorrow<str>, Clr: ValueSourceBorrow<Color>> Element for Label<Src, Clr> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let text = self.source.value();
+        let color = *(*self.color.value()).borrow();
+        let layout = TextLayout::new(&self.font, self.size, (*text).borrow());
 
-            let mut hasher = DefaultHasher::default();
-            (*self.source.value()).borrow().hash(&mut hasher);
-            (hasher.finish() % 64) as usize
-        };
-        let hash = hash as f32 / 64.;
+        let available = location.window_rect_size();
+        let origin = vec2(
+            (self.horizontal).offset(layout.size().x, available.width as _),
+            (self.vertical).offset(layout.size().y, available.height as _),
+        )
+        .round();
 
-        let padding = 1. - hash;
-        let location = location.subrect(Rectangle {
-            top_left: vec2(padding, 0.),
-            size: vec2(padding.mul_add(-2., 1.), 1.),
-        });
-        rect(Color::new_rgba(1., 1., 1., 0.5)).draw(pass, resources, location);
+        layout.draw(pass, resources, location, origin, color);
     }
 }
 
-impl<Src: ContainsShared + HandleEvent> HandleEvent for Label<Src> {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
+impl<Src: ContainsShared + HandleEvent, Clr: ContainsShared + HandleEvent> HandleEvent
+    for Label<Src, Clr>
+{
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
         match *event {
-            Event::SharedUpdated(addr) if self.source.contains_shared(addr) => {
+            Event::SharedUpdated(addr)
+                if self.source.contains_shared(addr) || self.color.contains_shared(addr) =>
+            {
                 signaler.send(Signal::Redraw);
             }
 
             _ => {}
         }
 
-        self.source.handle_event(signaler, event)
+        self.source.handle_event(signaler, event, location)?;
+        self.color.handle_event(signaler, event, location)
     }
 }
 
-pub const fn label<Src: ValueSourceBorrow<str>>(ra_fixture_source: Src) -> Label<Src> {
+pub fn label<Src: ValueSourceBorrow<str>>(ra_fixture_source: Src) -> Label<Src> {
     Label {
         source: ra_fixture_source,
+        color: Color::WHITE,
+        font: Font::default(),
+        size: DEFAULT_SIZE,
+        horizontal: Alignment::default(),
+        vertical: Alignment::default(),
     }
 }
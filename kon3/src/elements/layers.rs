@@ -1,3 +1,4 @@
+use crate::app::FocusId;
 use crate::app::Signaler;
 use crate::drawer::resources::Resources;
 use crate::drawer::DrawPass;
@@ -18,6 +19,12 @@ impl<A: Element, B: Element> Element for Layers<(A, B)> {
         a.draw(pass, resources, location);
         b.draw(pass, resources, location);
     }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        let (a, b) = &self.elements;
+        a.focus_order(order);
+        b.focus_order(order);
+    }
 }
 
 impl<A: Element, B: Element, C: Element> Element for Layers<(A, B, C)> {
@@ -27,14 +34,26 @@ impl<A: Element, B: Element, C: Element> Element for Layers<(A, B, C)> {
         b.draw(pass, resources, location);
         c.draw(pass, resources, location);
     }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        let (a, b, c) = &self.elements;
+        a.focus_order(order);
+        b.focus_order(order);
+        c.focus_order(order);
+    }
 }
 
 impl<Es: HandleEvent> HandleEvent for Layers<Es>
 where
     for<'s> ReversedTuple<&'s Es>: HandleEvent,
 {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
-        ReversedTuple(&self.elements).handle_event(signaler, event)
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        ReversedTuple(&self.elements).handle_event(signaler, event, location)
     }
 }
 
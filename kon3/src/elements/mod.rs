@@ -1,11 +1,34 @@
 pub mod events;
 
+mod button;
+mod cached;
+mod checkbox;
+mod clip;
+mod image;
+mod interaction;
 mod label;
 mod layers;
 mod rect;
+mod rounded_rect;
+mod scroll;
+mod sizing;
+mod slider;
 mod split;
+mod sprite;
+mod text_input;
 
+pub use button::*;
+pub use cached::*;
+pub use checkbox::*;
+pub use clip::*;
+pub use image::*;
 pub use label::*;
 pub use layers::*;
 pub use rect::*;
+pub use rounded_rect::*;
+pub use scroll::*;
+pub use sizing::*;
+pub use slider::*;
 pub use split::*;
+pub use sprite::*;
+pub use text_input::*;
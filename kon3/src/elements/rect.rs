@@ -1,9 +1,12 @@
+use crate::app::Signal;
+use crate::app::Signaler;
 use crate::drawer::resources::Resources;
 use crate::drawer::DrawPass;
 use crate::resources::mesh::DefaultTexture;
 use crate::resources::mesh::NoGlobalTransform;
 use crate::resources::mesh::UnitSquareTopLeft;
 use crate::values::ValueSourceBorrow;
+use crate::ContainsShared;
 use crate::Element;
 use crate::Event;
 use crate::EventResult;
@@ -11,7 +14,7 @@ use crate::HandleEvent;
 use crate::LocationRect;
 use crate::MeshDrawingInfo;
 use std::borrow::Borrow;
-use sww::shaders::mesh::BindGroups;
+use std::sync::Arc;
 use sww::shaders::mesh::Rectangle;
 use sww::shaders::mesh::Transform;
 use sww::Color;
@@ -29,23 +32,30 @@ impl<Clr: ValueSourceBorrow<Color>> Element for Rect<Clr> {
         pass.mesh().draw(
             &MeshDrawingInfo {
                 mesh: resources.get::<UnitSquareTopLeft>(),
-                bind_groups: BindGroups {
-                    bind_group0: resources.get::<NoGlobalTransform>(),
-                    bind_group1: resources.get::<DefaultTexture>(),
-                },
+                bind_group0: resources.get::<NoGlobalTransform>(),
+                bind_group1: Arc::clone(resources.get::<DefaultTexture>()),
             },
             transform,
         );
     }
 }
 
-impl<Clr> HandleEvent for Rect<Clr> {
+impl<Clr: ContainsShared + HandleEvent> HandleEvent for Rect<Clr> {
     fn handle_event(
         &self,
-        _signal_sender: &crate::prelude::Signaler,
-        _event: &Event,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
     ) -> EventResult {
-        Ok(())
+        match *event {
+            Event::SharedUpdated(addr) if self.color.contains_shared(addr) => {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.color.handle_event(signaler, event, location)
     }
 }
 
@@ -0,0 +1,119 @@
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrow;
+use crate::ContainsShared;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use std::borrow::Borrow;
+use sww::shaders::sdf::SdfInstance;
+use sww::Color;
+use sww::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// Offset from the rect in pixels.
+    pub offset: Vec2,
+    /// Distance in pixels over which the shadow fades out.
+    pub blur: f32,
+    pub color: Color,
+}
+
+/// An anti-aliased rect with rounded corners, an optional border and a drop shadow.
+pub struct RoundedRect<Clr> {
+    color: Clr,
+    corner_radii: [f32; 4],
+    border: Option<(f32, Color)>,
+    shadow: Option<Shadow>,
+}
+
+impl<Clr> RoundedRect<Clr> {
+    pub fn radius(self, radius: f32) -> Self {
+        self.corner_radii([radius; 4])
+    }
+
+    /// Radii of the top left, top right, bottom right and bottom left corners in pixels.
+    pub fn corner_radii(self, corner_radii: [f32; 4]) -> Self {
+        Self {
+            corner_radii,
+            ..self
+        }
+    }
+
+    /// A border of `width` pixels drawn inside of the rect.
+    pub fn border(self, width: f32, color: Color) -> Self {
+        Self {
+            border: Some((width, color)),
+            ..self
+        }
+    }
+
+    pub fn shadow(self, shadow: Shadow) -> Self {
+        Self {
+            shadow: Some(shadow),
+            ..self
+        }
+    }
+}
+
+impl<Clr: ValueSourceBorrow<Color>> Element for RoundedRect<Clr> {
+    fn draw(&self, pass: &mut DrawPass, _resources: &Resources, location: LocationRect) {
+        let size = location.rect().size / location.pixel_size();
+        if size.x <= 0. || size.y <= 0. {
+            return;
+        }
+
+        let mut sdf = pass.sdf();
+
+        if let Some(shadow) = self.shadow {
+            let location = location.pixel_subrect(shadow.offset, size);
+            sdf.draw(
+                SdfInstance::new(location.rect(), size, shadow.color)
+                    .with_corner_radii(self.corner_radii)
+                    .with_blur(shadow.blur),
+            );
+        }
+
+        let color = *(*self.color.value()).borrow();
+        let mut instance =
+            SdfInstance::new(location.rect(), size, color).with_corner_radii(self.corner_radii);
+        if let Some((width, color)) = self.border {
+            instance = instance.with_border(width, color);
+        }
+        sdf.draw(instance);
+    }
+}
+
+impl<Clr: ContainsShared + HandleEvent> HandleEvent for RoundedRect<Clr> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        match *event {
+            Event::SharedUpdated(addr) if self.color.contains_shared(addr) => {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.color.handle_event(signaler, event, location)
+    }
+}
+
+pub const fn rounded_rect<Clr: ValueSourceBorrow<Color>>(
+    ra_fixture_color: Clr,
+) -> RoundedRect<Clr> {
+    RoundedRect {
+        color: ra_fixture_color,
+        corner_radii: [0.; 4],
+        border: None,
+        shadow: None,
+    }
+}
@@ -0,0 +1,236 @@
+use crate::app::FocusId;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::dispatch_event;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrowMut;
+use crate::Consume;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationPoint;
+use crate::LocationRect;
+use parking_lot::Mutex;
+use std::borrow::Borrow;
+use std::borrow::BorrowMut;
+use sww::vec2;
+use sww::window::event::IntSize;
+use sww::window::event::MouseButton;
+use sww::window::event::MouseScrollDelta;
+use sww::DVec2;
+
+/// Pixels scrolled per line of a mouse wheel.
+const LINE_HEIGHT: f32 = 40.;
+/// Distance in pixels the pointer has to move while pressed before dragging scrolls.
+const DRAG_THRESHOLD: f32 = 4.;
+
+#[derive(Clone, Copy)]
+struct Drag {
+    start_y: f32,
+    start_offset: f32,
+    dragging: bool,
+}
+
+/// A vertically scrolling viewport over `element`, which is laid out as tall as its fixed or
+/// minimum [`SplitSize`](super::SplitSize), but at least as tall as the viewport.
+///
+/// The source holds the offset of the viewport from the top of the content in pixels.
+pub struct Scroll<E, Src> {
+    element: E,
+    offset: Mutex<Src>,
+    drag: Mutex<Option<Drag>>,
+}
+
+impl<E: Element, Src: ValueSourceBorrowMut<f32>> Scroll<E, Src> {
+    fn content_height(&self, location: LocationRect) -> f32 {
+        let split_size = self.element.split_size();
+        let height = location.window_rect_size().height as f32;
+        split_size.fixed.unwrap_or(split_size.min).max(height)
+    }
+
+    fn max_offset(&self, location: LocationRect) -> f32 {
+        self.content_height(location) - location.window_rect_size().height as f32
+    }
+
+    fn offset(&self, location: LocationRect) -> f32 {
+        let offset = *(*self.offset.lock().value()).borrow();
+        offset.clamp(0., self.max_offset(location))
+    }
+
+    fn set_offset(&self, signaler: &Signaler, location: LocationRect, offset: f32) {
+        let offset = offset.clamp(0., self.max_offset(location));
+
+        let mut source = self.offset.lock();
+        let mut value = source.value_mut(signaler);
+        *BorrowMut::<f32>::borrow_mut(&mut *value) = offset;
+    }
+
+    fn content_location(&self, location: LocationRect) -> LocationRect {
+        let width = location.window_rect_size().width as f32;
+        location.pixel_subrect(
+            vec2(0., -self.offset(location)),
+            vec2(width, self.content_height(location)),
+        )
+    }
+
+    /// Returns `false` if `event` is part of a drag that scrolls and shouldn't reach the element.
+    fn handle_drag(&self, signaler: &Signaler, event: &Event, location: LocationRect) -> bool {
+        let y = |point: LocationPoint| location.pixel_offset(point).y;
+
+        match *event {
+            Event::PointerDown { point, button }
+                if button == MouseButton::Left && location.contains(point) =>
+            {
+                *self.drag.lock() = Some(Drag {
+                    start_y: y(point),
+                    start_offset: self.offset(location),
+                    dragging: false,
+                });
+            }
+
+            Event::PointerMove { point, .. } => {
+                let Some(mut drag) = *self.drag.lock() else {
+                    return true;
+                };
+
+                let distance = y(point) - drag.start_y;
+                if !drag.dragging && distance.abs() >= DRAG_THRESHOLD {
+                    drag.dragging = true;
+                    // releases what the press started, where it can't count as a click
+                    let release = Event::PointerUp {
+                        point: nowhere(point.window_size()),
+                        button: MouseButton::Left,
+                    };
+                    let content_location = self.content_location(location);
+                    _ = dispatch_event(&self.element, signaler, &release, content_location);
+                }
+                if drag.dragging {
+                    self.set_offset(signaler, location, drag.start_offset - distance);
+                }
+                *self.drag.lock() = Some(drag);
+                return !drag.dragging;
+            }
+
+            // a drag that scrolled is kept until its click
+            Event::PointerUp {
+                button: MouseButton::Left,
+                ..
+            } => {
+                let mut drag = self.drag.lock();
+                if drag.is_some_and(|drag| drag.dragging) {
+                    return false;
+                }
+                *drag = None;
+            }
+
+            Event::Click {
+                button: MouseButton::Left,
+                ..
+            } => {
+                return !self.drag.lock().take().is_some_and(|drag| drag.dragging);
+            }
+
+            _ => {}
+        }
+
+        true
+    }
+}
+
+impl<E: Element, Src: ValueSourceBorrowMut<f32>> Element for Scroll<E, Src> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let content_location = self.content_location(location);
+        pass.clipped(location, |pass| {
+            self.element.draw(pass, resources, content_location);
+        });
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: Element, Src: ValueSourceBorrowMut<f32>> HandleEvent for Scroll<E, Src> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        if !self.handle_drag(signaler, event, location) {
+            return self.offset.lock().handle_event(signaler, event, location);
+        }
+
+        let content_location = self.content_location(location);
+        match *event {
+            Event::SharedUpdated(addr) if self.offset.lock().contains_shared(addr) => {
+                signaler.send(Signal::Redraw);
+            }
+
+            // hidden content can't be pointed at, the pointer leaves it at the viewport border
+            Event::PointerMove { point, previous } if !location.contains(point) => {
+                if previous.is_some_and(|previous| location.contains(previous)) {
+                    let leave = Event::PointerLeave {
+                        point: nowhere(point.window_size()),
+                    };
+                    dispatch_event(&self.element, signaler, &leave, content_location)?;
+                }
+                return self.offset.lock().handle_event(signaler, event, location);
+            }
+
+            Event::PointerMove { point, previous } => {
+                let previous = previous.filter(|&previous| location.contains(previous));
+                let moved = Event::PointerMove { point, previous };
+                dispatch_event(&self.element, signaler, &moved, content_location)?;
+                return self.offset.lock().handle_event(signaler, event, location);
+            }
+
+            Event::Click { point, .. }
+            | Event::PointerDown { point, .. }
+            | Event::PointerEnter { point }
+            | Event::Scroll { point, .. }
+                if !location.contains(point) =>
+            {
+                return self.offset.lock().handle_event(signaler, event, location);
+            }
+
+            _ => {}
+        }
+
+        dispatch_event(&self.element, signaler, event, content_location)?;
+
+        if let Event::Scroll { delta, .. } = *event {
+            let delta = match delta {
+                MouseScrollDelta::LineDelta(_, y) => y * LINE_HEIGHT,
+                MouseScrollDelta::PixelDelta(position) => position.y as f32,
+            };
+
+            let offset = self.offset(location);
+            let max_offset = self.max_offset(location);
+            if delta != 0. && (delta > 0. && offset > 0. || delta < 0. && offset < max_offset) {
+                self.set_offset(signaler, location, offset - delta);
+                return Err(Consume);
+            }
+        }
+
+        self.offset.lock().handle_event(signaler, event, location)
+    }
+}
+
+pub fn scroll<E: Element, Src: ValueSourceBorrowMut<f32>>(
+    ra_fixture_element: E,
+    ra_fixture_offset: Src,
+) -> Scroll<E, Src> {
+    Scroll {
+        element: ra_fixture_element,
+        offset: Mutex::new(ra_fixture_offset),
+        drag: Mutex::new(None),
+    }
+}
+
+/// A point outside of every location.
+fn nowhere(window_size: IntSize) -> LocationPoint {
+    LocationPoint::new(DVec2::INFINITY, window_size)
+}
@@ -0,0 +1,154 @@
+use crate::app::FocusId;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrow;
+use crate::ContainsShared;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use std::borrow::Borrow;
+
+/// How much space an element takes up along the axis of a [`Split`](super::Split), in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitSize {
+    /// Share of the space left after fixed-size elements.
+    pub weight: f32,
+    pub fixed: Option<f32>,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for SplitSize {
+    fn default() -> Self {
+        Self {
+            weight: 1.,
+            fixed: None,
+            min: 0.,
+            max: f32::INFINITY,
+        }
+    }
+}
+
+impl SplitSize {
+    pub fn clamp(self, size: f32) -> f32 {
+        size.min(self.max).max(self.min)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SizingKind {
+    Weight,
+    Fixed,
+    Min,
+    Max,
+}
+
+pub struct Sizing<E, Src> {
+    element: E,
+    kind: SizingKind,
+    value: Src,
+}
+
+impl<E: Element, Src: ValueSourceBorrow<f32> + ContainsShared + HandleEvent> Element
+    for Sizing<E, Src>
+{
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        self.element.draw(pass, resources, location);
+    }
+
+    fn split_size(&self) -> SplitSize {
+        let value = *(*self.value.value()).borrow();
+        let split_size = self.element.split_size();
+
+        match self.kind {
+            SizingKind::Weight => SplitSize {
+                weight: value,
+                ..split_size
+            },
+            SizingKind::Fixed => SplitSize {
+                fixed: Some(value),
+                ..split_size
+            },
+            SizingKind::Min => SplitSize {
+                min: value,
+                ..split_size
+            },
+            SizingKind::Max => SplitSize {
+                max: value,
+                ..split_size
+            },
+        }
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.element.focus_order(order);
+    }
+}
+
+impl<E: HandleEvent, Src: ContainsShared + HandleEvent> HandleEvent for Sizing<E, Src> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        match *event {
+            Event::SharedUpdated(addr) if self.value.contains_shared(addr) => {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.value.handle_event(signaler, event, location)?;
+        self.element.handle_event(signaler, event, location)
+    }
+}
+
+pub const fn weight<E: Element, Src: ValueSourceBorrow<f32>>(
+    ra_fixture_element: E,
+    ra_fixture_weight: Src,
+) -> Sizing<E, Src> {
+    Sizing {
+        element: ra_fixture_element,
+        kind: SizingKind::Weight,
+        value: ra_fixture_weight,
+    }
+}
+
+pub const fn fixed<E: Element, Src: ValueSourceBorrow<f32>>(
+    ra_fixture_element: E,
+    ra_fixture_size: Src,
+) -> Sizing<E, Src> {
+    Sizing {
+        element: ra_fixture_element,
+        kind: SizingKind::Fixed,
+        value: ra_fixture_size,
+    }
+}
+
+pub const fn min_size<E: Element, Src: ValueSourceBorrow<f32>>(
+    ra_fixture_element: E,
+    ra_fixture_size: Src,
+) -> Sizing<E, Src> {
+    Sizing {
+        element: ra_fixture_element,
+        kind: SizingKind::Min,
+        value: ra_fixture_size,
+    }
+}
+
+pub const fn max_size<E: Element, Src: ValueSourceBorrow<f32>>(
+    ra_fixture_element: E,
+    ra_fixture_size: Src,
+) -> Sizing<E, Src> {
+    Sizing {
+        element: ra_fixture_element,
+        kind: SizingKind::Max,
+        value: ra_fixture_size,
+    }
+}
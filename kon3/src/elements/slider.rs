@@ -0,0 +1,135 @@
+use super::interaction::Interaction;
+use super::interaction::ACCENT_COLOR;
+use super::interaction::NORMAL_COLOR;
+use super::rounded_rect;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrowMut;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationPoint;
+use crate::LocationRect;
+use parking_lot::Mutex;
+use std::borrow::Borrow;
+use std::borrow::BorrowMut;
+use std::ops::RangeInclusive;
+use sww::vec2;
+
+const TRACK_HEIGHT: f32 = 4.;
+const KNOB_WIDTH: f32 = 12.;
+
+pub struct Slider<Src> {
+    source: Mutex<Src>,
+    range: RangeInclusive<f32>,
+    interaction: Interaction,
+}
+
+impl<Src: ValueSourceBorrowMut<f32>> Slider<Src> {
+    /// Position of the value within the range, `0` if the range is empty.
+    fn fraction(&self) -> f32 {
+        let value = *(*self.source.lock().value()).borrow();
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let fraction = (value - start) / (end - start);
+        if fraction.is_nan() || start == end {
+            0.
+        } else {
+            fraction.clamp(0., 1.)
+        }
+    }
+
+    fn set_from_point(&self, signaler: &Signaler, location: LocationRect, point: LocationPoint) {
+        let width = track_width(location.window_rect_size().width as f32);
+        let x = location.pixel_offset(point).x - KNOB_WIDTH / 2.;
+        let fraction = if width > 0. {
+            (x / width).clamp(0., 1.)
+        } else {
+            0.
+        };
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        let mut source = self.source.lock();
+        let mut value = source.value_mut(signaler);
+        *BorrowMut::<f32>::borrow_mut(&mut *value) = start + (end - start) * fraction;
+    }
+}
+
+impl<Src: ValueSourceBorrowMut<f32>> Element for Slider<Src> {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let size = location.window_rect_size();
+        let (width, height) = (size.width as f32, size.height as f32);
+        let track_width = track_width(width);
+        let knob_x = (track_width * self.fraction()).round();
+
+        let track_y = ((height - TRACK_HEIGHT) / 2.).round();
+        let track_start = KNOB_WIDTH / 2.;
+        rounded_rect(NORMAL_COLOR).radius(TRACK_HEIGHT / 2.).draw(
+            pass,
+            resources,
+            location.pixel_subrect(vec2(track_start, track_y), vec2(track_width, TRACK_HEIGHT)),
+        );
+        rounded_rect(ACCENT_COLOR).radius(TRACK_HEIGHT / 2.).draw(
+            pass,
+            resources,
+            location.pixel_subrect(vec2(track_start, track_y), vec2(knob_x, TRACK_HEIGHT)),
+        );
+
+        let knob_height = (height * 0.6).round();
+        rounded_rect(self.interaction.knob_color())
+            .radius(KNOB_WIDTH / 2.)
+            .draw(
+                pass,
+                resources,
+                location.pixel_subrect(
+                    vec2(knob_x, ((height - knob_height) / 2.).round()),
+                    vec2(KNOB_WIDTH, knob_height),
+                ),
+            );
+    }
+}
+
+impl<Src: ValueSourceBorrowMut<f32>> HandleEvent for Slider<Src> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        self.interaction.handle_event(signaler, event, location);
+
+        match *event {
+            Event::PointerDown { point, .. } | Event::PointerMove { point, .. }
+                if self.interaction.is_pressed() =>
+            {
+                self.set_from_point(signaler, location, point);
+            }
+
+            Event::SharedUpdated(addr) if self.source.lock().contains_shared(addr) => {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.source.lock().handle_event(signaler, event, location)
+    }
+}
+
+/// Distance the knob can move in a slider `width` pixels wide.
+fn track_width(width: f32) -> f32 {
+    (width - KNOB_WIDTH).max(0.)
+}
+
+pub fn slider<Src: ValueSourceBorrowMut<f32>>(
+    ra_fixture_source: Src,
+    ra_fixture_range: RangeInclusive<f32>,
+) -> Slider<Src> {
+    Slider {
+        source: Mutex::new(ra_fixture_source),
+        range: ra_fixture_range,
+        interaction: Interaction::default(),
+    }
+}
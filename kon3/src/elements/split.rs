@@ -1,3 +1,7 @@
+use super::SplitSize;
+use crate::app::FocusId;
+use crate::app::Signaler;
+use crate::dispatch_event;
 use crate::drawer::DrawPass;
 use crate::resources::Resources;
 use crate::values::AutoValueSource;
@@ -10,7 +14,6 @@ use crate::LocationRect;
 use std::borrow::Borrow;
 use sww::shaders::mesh::Rectangle;
 use sww::vec2;
-use sww::Vec2;
 
 #[derive(Clone, Copy)]
 pub enum SplitType {
@@ -21,6 +24,24 @@ pub enum SplitType {
 
 impl AutoValueSource for SplitType {}
 
+impl SplitType {
+    /// Resolves [`SplitType::Adaptive`] into a horizontal split for wide locations and a vertical one for tall.
+    pub fn resolve(self, location: LocationRect) -> Self {
+        match self {
+            Self::Adaptive => {
+                let size = location.window_rect_size();
+                if size.width >= size.height {
+                    Self::Horizontal
+                } else {
+                    Self::Vertical
+                }
+            }
+
+            ty => ty,
+        }
+    }
+}
+
 pub struct Split<Ty, Es> {
     ty: Ty,
     elements: Es,
@@ -41,12 +62,39 @@ macro_rules! impl_tuple {
                 let ($($T),+) = &self.elements;
                 draw_helper(
                     *(*self.ty.value()).borrow(),
-                    &[$((1, $T)),+],
+                    &[$($T as &dyn Element),+],
                     pass,
                     resources,
                     location,
                 );
             }
+
+            fn focus_order(&self, order: &mut Vec<FocusId>) {
+                #[allow(non_snake_case)]
+                let ($($T),+) = &self.elements;
+                $( $T.focus_order(order); )+
+            }
+        }
+
+        impl<Ty: ValueSourceBorrow<SplitType>, $($T: Element),+> HandleEvent
+            for Split<Ty, ($($T),+)>
+        {
+            fn handle_event(
+                &self,
+                signaler: &Signaler,
+                event: &Event,
+                location: LocationRect,
+            ) -> EventResult {
+                #[allow(non_snake_case)]
+                let ($($T),+) = &self.elements;
+                handle_event_helper(
+                    *(*self.ty.value()).borrow(),
+                    &[$($T as &dyn Element),+],
+                    signaler,
+                    event,
+                    location,
+                )
+            }
         }
     };
 }
@@ -65,38 +113,132 @@ impl_tuple!(A B C D E F G H I J K L);
 
 fn draw_helper(
     ty: SplitType,
-    elements: &[(usize, &dyn Element)],
+    elements: &[&dyn Element],
     pass: &mut DrawPass,
     resources: &Resources,
     location: LocationRect,
 ) {
-    let total_weight: usize = elements.iter().map(|&(weight, _)| weight).sum();
-    let fraction = 1. / total_weight as f32;
+    for (&element, location) in elements.iter().zip(child_locations(ty, elements, location)) {
+        element.draw(pass, resources, location);
+    }
+}
 
-    let (rect_fraction_size, rect_fraction_offset) = match ty {
-        SplitType::Vertical => (vec2(1., fraction), vec2(0., fraction)),
-        SplitType::Horizontal => (vec2(fraction, 1.), vec2(fraction, 0.)),
-        SplitType::Adaptive => todo!(),
-    };
+fn handle_event_helper(
+    ty: SplitType,
+    elements: &[&dyn Element],
+    signaler: &Signaler,
+    event: &Event,
+    location: LocationRect,
+) -> EventResult {
+    for (&element, location) in elements.iter().zip(child_locations(ty, elements, location)) {
+        dispatch_event(element, signaler, event, location)?;
+    }
+    Ok(())
+}
 
-    let mut top_left = Vec2::ZERO;
-    for (weight, element) in elements.iter().copied() {
-        let weight = weight as f32;
-        let size = rect_fraction_size * weight;
-        let offset = rect_fraction_offset * weight;
+fn child_locations(
+    ty: SplitType,
+    elements: &[&dyn Element],
+    location: LocationRect,
+) -> Vec<LocationRect> {
+    let ty = ty.resolve(location);
+    let window_rect_size = location.window_rect_size();
+    let available = match ty {
+        SplitType::Vertical => window_rect_size.height,
+        SplitType::Horizontal => window_rect_size.width,
+        SplitType::Adaptive => unreachable!(),
+    } as f32;
 
-        element.draw(
-            pass,
-            resources,
-            location.subrect(Rectangle::new(top_left, size)),
-        );
-        top_left += offset;
-    }
+    let sizes = distribute(
+        &elements
+            .iter()
+            .map(|element| element.split_size())
+            .collect::<Vec<_>>(),
+        available,
+    );
+
+    let mut offset = 0.;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let (fraction_offset, fraction_size) = if available > 0. {
+                (offset / available, size / available)
+            } else {
+                (0., 0.)
+            };
+            offset += size;
+
+            location.subrect(match ty {
+                SplitType::Vertical => {
+                    Rectangle::new(vec2(0., fraction_offset), vec2(1., fraction_size))
+                }
+                SplitType::Horizontal => {
+                    Rectangle::new(vec2(fraction_offset, 0.), vec2(fraction_size, 1.))
+                }
+                SplitType::Adaptive => unreachable!(),
+            })
+        })
+        .collect()
 }
 
-impl<Ty, Es: HandleEvent> HandleEvent for Split<Ty, Es> {
-    fn handle_event(&self, signaler: &crate::prelude::Signaler, event: &Event) -> EventResult {
-        self.elements.handle_event(signaler, event)
+/// Distributes `available` pixels: fixed sizes first, then the rest by weight within min/max bounds.
+///
+/// Like flexbox, each round only freezes the sizes clamped in the direction of the total
+/// adjustment, so that growing to a min and shrinking to a max don't overflow together.
+fn distribute(split_sizes: &[SplitSize], available: f32) -> Vec<f32> {
+    let mut sizes: Vec<_> = (split_sizes.iter())
+        .map(|split_size| split_size.fixed.map(|fixed| split_size.clamp(fixed)))
+        .collect();
+
+    loop {
+        let free = (available - sizes.iter().flatten().sum::<f32>()).max(0.);
+        let total_weight: f32 = (split_sizes.iter().zip(&sizes))
+            .filter(|(_, size)| size.is_none())
+            .map(|(split_size, _)| split_size.weight)
+            .sum();
+        let share = |split_size: &SplitSize| {
+            if total_weight > 0. {
+                free * split_size.weight / total_weight
+            } else {
+                0.
+            }
+        };
+
+        let adjustments: Vec<_> = (split_sizes.iter().zip(&sizes))
+            .map(|(split_size, size)| {
+                size.is_none().then(|| {
+                    let flexible = share(split_size);
+                    split_size.clamp(flexible) - flexible
+                })
+            })
+            .collect();
+        let total_adjustment: f32 = adjustments.iter().flatten().sum();
+
+        let mut frozen_any = false;
+        for ((split_size, size), adjustment) in split_sizes.iter().zip(&mut sizes).zip(adjustments)
+        {
+            let Some(adjustment) = adjustment else {
+                continue;
+            };
+
+            let freeze = if total_adjustment > 0. {
+                adjustment > 0.
+            } else if total_adjustment < 0. {
+                adjustment < 0.
+            } else {
+                adjustment != 0.
+            };
+            if freeze {
+                *size = Some(split_size.clamp(share(split_size)));
+                frozen_any = true;
+            }
+        }
+
+        if !frozen_any {
+            return (split_sizes.iter().zip(sizes))
+                .map(|(split_size, size)| size.unwrap_or_else(|| share(split_size)))
+                .collect();
+        }
     }
 }
 
@@ -120,3 +262,84 @@ pub const fn column<Es>(ra_fixture_elements: Es) -> Split<SplitType, Es> {
         elements: ra_fixture_elements,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weight(weight: f32) -> SplitSize {
+        SplitSize {
+            weight,
+            ..Default::default()
+        }
+    }
+
+    fn fixed(fixed: f32) -> SplitSize {
+        SplitSize {
+            fixed: Some(fixed),
+            ..Default::default()
+        }
+    }
+
+    fn min(min: f32) -> SplitSize {
+        SplitSize {
+            min,
+            ..Default::default()
+        }
+    }
+
+    fn max(max: f32) -> SplitSize {
+        SplitSize {
+            max,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fixed_sizes_come_first() {
+        assert_eq!(
+            distribute(&[fixed(30.), weight(1.), weight(1.)], 100.),
+            [30., 35., 35.]
+        );
+    }
+
+    #[test]
+    fn weights_share_the_rest() {
+        assert_eq!(distribute(&[weight(1.), weight(3.)], 100.), [25., 75.]);
+    }
+
+    #[test]
+    fn min_takes_from_the_others() {
+        assert_eq!(
+            distribute(&[min(60.), weight(1.), weight(1.)], 100.),
+            [60., 20., 20.]
+        );
+    }
+
+    #[test]
+    fn max_gives_to_the_others() {
+        assert_eq!(
+            distribute(&[max(20.), weight(1.), weight(3.)], 100.),
+            [20., 20., 60.]
+        );
+    }
+
+    #[test]
+    fn fixed_is_clamped() {
+        let split_size = SplitSize {
+            max: 10.,
+            ..fixed(30.)
+        };
+        assert_eq!(distribute(&[split_size, weight(1.)], 100.), [10., 90.]);
+    }
+
+    #[test]
+    fn min_wins_over_max() {
+        assert_eq!(distribute(&[min(90.), max(20.)], 100.), [90., 10.]);
+    }
+
+    #[test]
+    fn max_leaves_room_above_a_min() {
+        assert_eq!(distribute(&[max(10.), min(20.)], 100.), [10., 90.]);
+    }
+}
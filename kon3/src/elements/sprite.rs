@@ -0,0 +1,160 @@
+use super::image::draw_texture;
+use super::image::TextureDraw;
+use super::Fit;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resource;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::values::ValueSourceBorrow;
+use crate::ContainsShared;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use sww::image::RgbaImage;
+use sww::media::Sampling;
+use sww::media::Sheet;
+use sww::media::SheetCoord;
+use sww::shaders::mesh::BindGroup1;
+use sww::shaders::mesh::BindGroupLayout1;
+use sww::window::RenderTarget;
+use sww::Color;
+use sww::Vec2;
+
+/// Describes a sprite sheet, usually implemented by a unit struct.
+pub trait SheetResource: 'static {
+    type Coord: SheetCoord<Output = Vec2>;
+
+    fn image() -> RgbaImage;
+
+    /// Nearest by default, since linear filtering blends in the neighbouring cells at the edges.
+    fn sampling() -> Sampling {
+        Sampling::NEAREST
+    }
+}
+
+/// Owns the texture of the sheet `S` and its bind group.
+pub struct SpriteSheet<S: SheetResource> {
+    sheet: Sheet<S::Coord>,
+    bind_group: Arc<BindGroup1>,
+}
+
+impl<S: SheetResource> SpriteSheet<S> {
+    pub fn sheet(&self) -> &Sheet<S::Coord> {
+        &self.sheet
+    }
+
+    pub fn bind_group(&self) -> &Arc<BindGroup1> {
+        &self.bind_group
+    }
+
+    /// Size of a single cell in pixels.
+    pub fn cell_size(&self) -> Vec2 {
+        self.sheet.size() * S::Coord::size()
+    }
+}
+
+impl<S: SheetResource> Resource for SpriteSheet<S> {
+    fn new(rw: &dyn RenderTarget) -> Self {
+        let sheet = Sheet::new(rw, S::image());
+        let bind_group = Arc::new(BindGroup1::from_bindings(
+            rw.device(),
+            BindGroupLayout1 {
+                texture: sheet.texture_view(),
+                texture_sampler: &S::sampling().make_sampler(rw.device()),
+            },
+        ));
+
+        Self { sheet, bind_group }
+    }
+}
+
+pub struct Sprite<S, Src, Clr = Color> {
+    coord: Src,
+    fit: Fit,
+    tint: Clr,
+    phantom: PhantomData<S>,
+}
+
+impl<S, Src, Clr> Sprite<S, Src, Clr> {
+    pub fn fit(self, fit: Fit) -> Self {
+        Self { fit, ..self }
+    }
+
+    pub fn tint<C: ValueSourceBorrow<Color>>(self, ra_fixture_tint: C) -> Sprite<S, Src, C> {
+        Sprite {
+            coord: self.coord,
+            fit: self.fit,
+            tint: ra_fixture_tint,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, Src, Clr> Element for Sprite<S, Src, Clr>
+where
+    S: SheetResource,
+    S::Coord: Copy,
+    Src: ValueSourceBorrow<S::Coord>,
+    Clr: ValueSourceBorrow<Color>,
+{
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        let sheet = resources.get::<SpriteSheet<S>>();
+        let coord = *(*self.coord.value()).borrow();
+
+        draw_texture(
+            pass,
+            resources,
+            location,
+            TextureDraw {
+                bind_group: Arc::clone(sheet.bind_group()),
+                image_size: sheet.cell_size(),
+                texture_rect: sheet.sheet().texture_rect(coord),
+                fit: self.fit,
+                tint: *(*self.tint.value()).borrow(),
+            },
+        );
+    }
+}
+
+impl<S, Src: ContainsShared + HandleEvent, Clr: ContainsShared + HandleEvent> HandleEvent
+    for Sprite<S, Src, Clr>
+{
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        match *event {
+            Event::SharedUpdated(addr)
+                if self.coord.contains_shared(addr) || self.tint.contains_shared(addr) =>
+            {
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        self.coord.handle_event(signaler, event, location)?;
+        self.tint.handle_event(signaler, event, location)
+    }
+}
+
+/// Draws the cell of the sheet `S` selected by `coord`.
+pub fn sprite<S: SheetResource, Src: ValueSourceBorrow<S::Coord>>(
+    _sheet: S,
+    ra_fixture_coord: Src,
+) -> Sprite<S, Src> {
+    Sprite {
+        coord: ra_fixture_coord,
+        fit: Fit::default(),
+        tint: Color::WHITE,
+        phantom: PhantomData,
+    }
+}
@@ -0,0 +1,420 @@
+use super::events::focusable;
+use super::events::Focusable;
+use super::rect;
+use crate::app::FocusId;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::text::Font;
+use crate::text::TextLayout;
+use crate::Consume;
+use crate::Element;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationPoint;
+use crate::LocationRect;
+use crate::Shared;
+use parking_lot::Mutex;
+use std::ops::Range;
+use sww::vec2;
+use sww::window::event::ElementState;
+use sww::window::event::Ime;
+use sww::window::event::IntSize;
+use sww::window::event::Key;
+use sww::window::event::KeyEvent;
+use sww::window::event::KeyboardModifiers;
+use sww::window::event::NamedKey;
+use sww::Color;
+
+const DEFAULT_SIZE: f32 = 16.;
+const PADDING: f32 = 4.;
+const BACKGROUND_COLOR: Color = Color::splat(0.15);
+const SELECTION_COLOR: Color = Color::new_rgba(0.2, 0.4, 0.9, 0.6);
+
+/// A single line text field, scrolled horizontally to keep the caret visible.
+pub struct TextInput {
+    field: Focusable<TextField>,
+}
+
+struct TextField {
+    text: Shared<String>,
+    /// Same as the one of the enclosing [`Focusable`].
+    id: FocusId,
+    font: Font,
+    size: f32,
+    color: Color,
+    state: Mutex<TextInputState>,
+}
+
+#[derive(Default)]
+struct TextInputState {
+    /// Byte index of the caret.
+    caret: usize,
+    /// Byte index of the other end of the selection, equal to `caret` if nothing is selected.
+    anchor: usize,
+    preedit: Option<String>,
+    clipboard: String,
+    focused: bool,
+    dragging: bool,
+    /// How far the text is scrolled to the left, in pixels.
+    scroll: f32,
+}
+
+impl TextInputState {
+    fn selection(&self) -> Range<usize> {
+        self.caret.min(self.anchor)..self.caret.max(self.anchor)
+    }
+
+    fn move_caret(&mut self, caret: usize, select: bool) {
+        self.caret = caret;
+        if !select {
+            self.anchor = caret;
+        }
+    }
+
+    /// Keeps the caret and the anchor valid after the text was changed elsewhere.
+    fn clamp(&mut self, text: &str) {
+        self.caret = floor_char_boundary(text, self.caret);
+        self.anchor = floor_char_boundary(text, self.anchor);
+    }
+}
+
+impl TextInput {
+    pub fn font(self, font: Font) -> Self {
+        self.map_field(|field| TextField { font, ..field })
+    }
+
+    /// Font size in pixels.
+    pub fn size(self, size: f32) -> Self {
+        self.map_field(|field| TextField { size, ..field })
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        self.map_field(|field| TextField { color, ..field })
+    }
+
+    pub const fn id(&self) -> FocusId {
+        self.field.id()
+    }
+
+    fn map_field(self, f: impl FnOnce(TextField) -> TextField) -> Self {
+        Self {
+            field: self.field.map(f),
+        }
+    }
+}
+
+impl TextField {
+    fn layout(&self, text: &str) -> TextLayout {
+        TextLayout::new(&self.font, self.size, text)
+    }
+
+    fn hit_index(&self, location: LocationRect, point: LocationPoint) -> usize {
+        let x = location.pixel_offset(point).x - PADDING + self.state.lock().scroll;
+        self.layout(&self.text.read()).hit_index(x)
+    }
+
+    fn replace_selection(&self, signaler: &Signaler, state: &mut TextInputState, insert: &str) {
+        let selection = state.selection();
+        (self.text.write(signaler)).replace_range(selection.clone(), insert);
+        state.move_caret(selection.start + insert.len(), false);
+    }
+
+    fn handle_key(
+        &self,
+        signaler: &Signaler,
+        event: &KeyEvent,
+        modifiers: KeyboardModifiers,
+    ) -> EventResult {
+        if event.state != ElementState::Pressed {
+            return Ok(());
+        }
+
+        let modifiers = modifiers.state();
+        let (control, shift) = (modifiers.control_key(), modifiers.shift_key());
+        let mut state = self.state.lock();
+        // the text may have been changed before its update was handled
+        state.clamp(&self.text.read());
+
+        match &event.logical_key {
+            Key::Named(NamedKey::ArrowLeft) => {
+                let selection = state.selection();
+                let caret = if selection.is_empty() || shift {
+                    previous_char_boundary(&self.text.read(), state.caret)
+                } else {
+                    selection.start
+                };
+                state.move_caret(caret, shift);
+            }
+
+            Key::Named(NamedKey::ArrowRight) => {
+                let selection = state.selection();
+                let caret = if selection.is_empty() || shift {
+                    next_char_boundary(&self.text.read(), state.caret)
+                } else {
+                    selection.end
+                };
+                state.move_caret(caret, shift);
+            }
+
+            Key::Named(NamedKey::Home) => state.move_caret(0, shift),
+
+            Key::Named(NamedKey::End) => {
+                let len = self.text.read().len();
+                state.move_caret(len, shift);
+            }
+
+            Key::Named(NamedKey::Backspace) => {
+                if state.selection().is_empty() {
+                    if state.caret == 0 {
+                        return Ok(());
+                    }
+                    state.anchor = previous_char_boundary(&self.text.read(), state.caret);
+                }
+                self.replace_selection(signaler, &mut state, "");
+            }
+
+            Key::Named(NamedKey::Delete) => {
+                if state.selection().is_empty() {
+                    let text = self.text.read();
+                    if state.caret == text.len() {
+                        return Ok(());
+                    }
+                    state.anchor = next_char_boundary(&text, state.caret);
+                }
+                self.replace_selection(signaler, &mut state, "");
+            }
+
+            Key::Character(c) if control => match c.to_lowercase().as_str() {
+                "a" => {
+                    state.anchor = 0;
+                    state.caret = self.text.read().len();
+                }
+
+                "c" => {
+                    state.clipboard = self.text.read()[state.selection()].to_owned();
+                }
+
+                "x" => {
+                    state.clipboard = self.text.read()[state.selection()].to_owned();
+                    self.replace_selection(signaler, &mut state, "");
+                }
+
+                "v" => {
+                    let clipboard = state.clipboard.clone();
+                    self.replace_selection(signaler, &mut state, &clipboard);
+                }
+
+                _ => return Ok(()),
+            },
+
+            _ => return Ok(()),
+        }
+
+        signaler.send(Signal::Redraw);
+        Err(Consume)
+    }
+}
+
+impl Element for TextInput {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        self.field.draw(pass, resources, location);
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.field.focus_order(order);
+    }
+}
+
+impl HandleEvent for TextInput {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        self.field.handle_event(signaler, event, location)
+    }
+}
+
+impl Element for TextField {
+    fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
+        rect(BACKGROUND_COLOR).draw(pass, resources, location);
+
+        let mut state = self.state.lock();
+        let text = self.text.read();
+        state.clamp(&text);
+        let preedit = state.preedit.clone().unwrap_or_default();
+
+        let mut display_text = text.clone();
+        display_text.insert_str(state.caret, &preedit);
+        let layout = self.layout(&display_text);
+        let caret = layout.caret_x(state.caret + preedit.len());
+        let IntSize { width, height } = location.window_rect_size();
+
+        // keeps the caret, which is a pixel wide, inside of the padding
+        let visible_width = (width as f32 - 2. * PADDING - 1.).max(0.);
+        let max_scroll = (layout.size().x - visible_width).max(0.);
+        state.scroll = (state.scroll.min(caret).max(caret - visible_width))
+            .clamp(0., max_scroll)
+            .round();
+
+        let display_index = |index: usize| {
+            if index > state.caret {
+                index + preedit.len()
+            } else {
+                index
+            }
+        };
+
+        let origin = vec2(
+            PADDING - state.scroll,
+            ((height as f32 - layout.size().y) / 2.).round(),
+        );
+        let line_size = vec2(0., layout.size().y);
+
+        pass.clipped(location, |pass| {
+            let selection = state.selection();
+            if !selection.is_empty() {
+                let start = layout.caret_x(display_index(selection.start));
+                let end = layout.caret_x(display_index(selection.end));
+                rect(SELECTION_COLOR).draw(
+                    pass,
+                    resources,
+                    location
+                        .pixel_subrect(origin + vec2(start, 0.), line_size + vec2(end - start, 0.)),
+                );
+            }
+
+            layout.draw(pass, resources, location, origin, self.color);
+
+            if !preedit.is_empty() {
+                let start = layout.caret_x(state.caret);
+                rect(self.color).draw(
+                    pass,
+                    resources,
+                    location
+                        .pixel_subrect(origin + vec2(start, line_size.y), vec2(caret - start, 1.)),
+                );
+            }
+
+            if state.focused {
+                rect(self.color).draw(
+                    pass,
+                    resources,
+                    location
+                        .pixel_subrect(origin + vec2(caret.round(), 0.), line_size + vec2(1., 0.)),
+                );
+            }
+        });
+    }
+}
+
+impl HandleEvent for TextField {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        match *event {
+            Event::SharedUpdated(addr) if addr == self.text.addr() => {
+                self.state.lock().clamp(&self.text.read());
+                signaler.send(Signal::Redraw);
+            }
+
+            Event::FocusChanged(focus) => {
+                let mut state = self.state.lock();
+                state.focused = focus == Some(self.id);
+                if !state.focused {
+                    state.preedit = None;
+                    state.dragging = false;
+                }
+            }
+
+            // focus is taken by the enclosing `Focusable`
+            Event::PointerDown { point, .. } if location.contains(point) => {
+                let caret = self.hit_index(location, point);
+                let mut state = self.state.lock();
+                state.move_caret(caret, false);
+                state.dragging = true;
+                signaler.send(Signal::Redraw);
+            }
+
+            Event::PointerMove { point, .. } if self.state.lock().dragging => {
+                let caret = self.hit_index(location, point);
+                self.state.lock().move_caret(caret, true);
+                signaler.send(Signal::Redraw);
+            }
+
+            Event::PointerUp { .. } => {
+                self.state.lock().dragging = false;
+            }
+
+            Event::Key {
+                ref event,
+                modifiers,
+            } if signaler.is_focused(self.id) => {
+                return self.handle_key(signaler, event, modifiers);
+            }
+
+            Event::Text(ref text) if signaler.is_focused(self.id) => {
+                let mut state = self.state.lock();
+                state.clamp(&self.text.read());
+                state.preedit = None;
+                self.replace_selection(signaler, &mut state, text);
+                signaler.send(Signal::Redraw);
+                return Err(Consume);
+            }
+
+            Event::Ime(Ime::Preedit(ref preedit, _)) if signaler.is_focused(self.id) => {
+                self.state.lock().preedit = (!preedit.is_empty()).then(|| preedit.clone());
+                signaler.send(Signal::Redraw);
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+pub fn text_input(ra_fixture_text: Shared<String>) -> TextInput {
+    let field = focusable(());
+    let id = field.id();
+
+    TextInput {
+        field: field.map(|()| TextField {
+            text: ra_fixture_text,
+            id,
+            font: Font::default(),
+            size: DEFAULT_SIZE,
+            color: Color::WHITE,
+            state: Default::default(),
+        }),
+    }
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn previous_char_boundary(text: &str, index: usize) -> usize {
+    text[..index]
+        .char_indices()
+        .next_back()
+        .map_or(0, |(i, _)| i)
+}
+
+fn next_char_boundary(text: &str, index: usize) -> usize {
+    text[index..]
+        .chars()
+        .next()
+        .map_or(index, |c| index + c.len_utf8())
+}
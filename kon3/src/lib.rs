@@ -2,14 +2,25 @@ pub mod app;
 pub mod elements;
 pub mod prelude;
 pub mod shared;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod text;
 pub mod values;
 
+use app::FocusId;
 use app::Signaler;
+use app::TimerId;
+use elements::SplitSize;
 use resources::Resources;
 use shared::Shared;
 use shared::SharedAddr;
 use std::sync::Arc;
+use std::time::Instant;
+use sww::window::event::Ime;
+use sww::window::event::KeyEvent;
+use sww::window::event::KeyboardModifiers;
 use sww::window::event::MouseButton;
+use sww::window::event::MouseScrollDelta;
 use values::ContainsShared;
 
 mod drawer;
@@ -18,13 +29,68 @@ mod location;
 pub use drawer::*;
 pub use location::*;
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum Event {
     Click {
         point: LocationPoint,
         button: MouseButton,
     },
+    PointerDown {
+        point: LocationPoint,
+        button: MouseButton,
+    },
+    PointerUp {
+        point: LocationPoint,
+        button: MouseButton,
+    },
+    /// `previous` is `None` if the pointer has just entered the window.
+    PointerMove {
+        point: LocationPoint,
+        previous: Option<LocationPoint>,
+    },
+    PointerEnter {
+        point: LocationPoint,
+    },
+    PointerLeave {
+        point: LocationPoint,
+    },
+    Scroll {
+        point: LocationPoint,
+        delta: MouseScrollDelta,
+    },
+    /// Sent to the whole tree; only the focused element should react.
+    Key {
+        event: KeyEvent,
+        modifiers: KeyboardModifiers,
+    },
+    /// Text typed while an element is focused, without control characters.
+    Text(String),
+    Ime(Ime),
+    FocusChanged(Option<FocusId>),
     SharedUpdated(SharedAddr),
+    /// Sent to the whole tree before drawing a frame, while [`Signal::AnimationFrame`] keeps
+    /// being requested.
+    ///
+    /// [`Signal::AnimationFrame`]: app::Signal::AnimationFrame
+    AnimationFrame(Instant),
+    /// Sent to the whole tree when a timer fires; only the element that set it should react.
+    Timer(TimerId),
+}
+
+impl Event {
+    /// `PointerEnter` or `PointerLeave` if this event moves the pointer across the border of `location`.
+    pub fn crossing(&self, location: LocationRect) -> Option<Self> {
+        let Self::PointerMove { point, previous } = *self else {
+            return None;
+        };
+
+        let was_inside = previous.is_some_and(|previous| location.contains(previous));
+        match (was_inside, location.contains(point)) {
+            (false, true) => Some(Self::PointerEnter { point }),
+            (true, false) => Some(Self::PointerLeave { point }),
+            _ => None,
+        }
+    }
 }
 
 pub struct Consume;
@@ -53,24 +119,67 @@ impl IntoEventResult for Consume {
     }
 }
 
-// TODO add `location: LocationRect`?
 pub trait HandleEvent {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult;
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult;
+}
+
+/// Sends `event` to a child at its own `location`, such as a part of a [`Split`](elements::Split).
+/// Pointer crossings are derived for the child itself instead of being inherited from its parent.
+pub fn dispatch_event(
+    element: &(impl HandleEvent + ?Sized),
+    signaler: &Signaler,
+    event: &Event,
+    location: LocationRect,
+) -> EventResult {
+    if let Some(crossing) = event.crossing(location) {
+        element.handle_event(signaler, &crossing, location)?;
+    }
+
+    match *event {
+        Event::PointerEnter { .. } => Ok(()),
+        Event::PointerLeave { point } if !location.contains(point) => Ok(()),
+        _ => element.handle_event(signaler, event, location),
+    }
 }
 
 pub trait Element: HandleEvent {
     fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect);
+
+    fn split_size(&self) -> SplitSize {
+        SplitSize::default()
+    }
+
+    /// Appends focusable elements in tree order, for Tab traversal.
+    fn focus_order(&self, _order: &mut Vec<FocusId>) {}
 }
 
 impl<T: Element + ?Sized> Element for Arc<T> {
     fn draw(&self, pass: &mut DrawPass, resources: &Resources, location: LocationRect) {
         self.as_ref().draw(pass, resources, location);
     }
+
+    fn split_size(&self) -> SplitSize {
+        self.as_ref().split_size()
+    }
+
+    fn focus_order(&self, order: &mut Vec<FocusId>) {
+        self.as_ref().focus_order(order);
+    }
 }
 
 impl<T: HandleEvent + ?Sized> HandleEvent for Arc<T> {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
-        self.as_ref().handle_event(signaler, event)
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        self.as_ref().handle_event(signaler, event, location)
     }
 }
 
@@ -79,18 +188,18 @@ pub struct ReversedTuple<T>(pub T);
 macro_rules! impl_tuple {
     ( $($T:ident)+ | $($Reversed:tt)+ ) => {
         impl<$($T: HandleEvent),+> HandleEvent for ($($T),+) {
-            fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
+            fn handle_event(&self, signaler: &Signaler, event: &Event, location: LocationRect) -> EventResult {
                 #[allow(non_snake_case)]
                 let ($($T),+) = self;
 
-                $( $T.handle_event(signaler, event)?; )+
+                $( $T.handle_event(signaler, event, location)?; )+
                 Ok(())
             }
         }
 
         impl<$($T: HandleEvent),+> HandleEvent for ReversedTuple<&($($T),+)> {
-            fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
-                $( self.0 .$Reversed.handle_event(signaler, event)?; )+
+            fn handle_event(&self, signaler: &Signaler, event: &Event, location: LocationRect) -> EventResult {
+                $( self.0 .$Reversed.handle_event(signaler, event, location)?; )+
                 Ok(())
             }
         }
@@ -1,8 +1,10 @@
+use sww::dvec2;
 use sww::shaders::mesh::Rectangle;
 use sww::vec2;
 use sww::window::event::IntPosition;
 use sww::window::event::IntSize;
 use sww::DVec2;
+use sww::Vec2;
 
 #[derive(Clone, Copy)]
 pub struct LocationPoint {
@@ -15,6 +17,12 @@ impl LocationPoint {
         Self { point, window_size }
     }
 
+    pub fn from_window_point(window_point: DVec2, window_size: IntSize) -> Self {
+        let window_size_f = dvec2(window_size.width as _, window_size.height as _);
+        let point = window_point / window_size_f * 2. - DVec2::ONE;
+        Self::new(dvec2(point.x, -point.y), window_size)
+    }
+
     pub const fn point(self) -> DVec2 {
         self.point
     }
@@ -24,7 +32,7 @@ impl LocationPoint {
     }
 
     pub fn window_point(self) -> IntPosition {
-        let point = (self.point + DVec2::ONE) / 2.;
+        let point = (dvec2(self.point.x, -self.point.y) + DVec2::ONE) / 2.;
         IntPosition::new(
             (point.x * self.window_size.width as f64) as _,
             (point.y * self.window_size.height as f64) as _,
@@ -32,7 +40,7 @@ impl LocationPoint {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct LocationRect {
     rect: Rectangle,
     window_size: IntSize,
@@ -59,13 +67,47 @@ impl LocationRect {
     }
 
     pub fn window_rect_size(self) -> IntSize {
-        let rect_size = self.rect.size / 2.;
+        let rect_size = self.rect.size.abs() / 2.;
         IntSize::new(
             (rect_size.x * self.window_size.width as f32) as _,
             (rect_size.y * self.window_size.height as f32) as _,
         )
     }
 
+    /// Size of a single window pixel, with `y` pointing down.
+    pub fn pixel_size(self) -> Vec2 {
+        vec2(
+            2. / self.window_size.width as f32,
+            -2. / self.window_size.height as f32,
+        )
+    }
+
+    /// A subrect given in pixels relative to the top left corner.
+    pub fn pixel_subrect(self, top_left: Vec2, size: Vec2) -> Self {
+        let pixel_size = self.pixel_size();
+        Self {
+            rect: Rectangle::new(
+                self.rect.top_left + top_left * pixel_size,
+                size * pixel_size,
+            ),
+            window_size: self.window_size,
+        }
+    }
+
+    /// Position of `point` in pixels relative to the top left corner.
+    pub fn pixel_offset(self, point: LocationPoint) -> Vec2 {
+        (point.point().as_vec2() - self.rect.top_left) / self.pixel_size()
+    }
+
+    pub fn contains(self, point: LocationPoint) -> bool {
+        let point = point.point().as_vec2();
+        let a = self.rect.top_left;
+        let b = a + self.rect.size;
+
+        let contains = |a: f32, b: f32, x: f32| a.min(b) <= x && x < a.max(b);
+        contains(a.x, b.x, point.x) && contains(a.y, b.y, point.y)
+    }
+
     pub fn subrect(self, rect: Rectangle) -> Self {
         Self {
             rect: self.rect.subrect(rect),
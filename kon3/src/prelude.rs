@@ -3,9 +3,11 @@ pub use crate::elements::events::*;
 pub use crate::elements::*;
 pub use crate::resources::*;
 pub use crate::shared::*;
+pub use crate::text::*;
 pub use crate::values::*;
 pub use crate::*;
 pub use sww::window::window_attributes;
 pub use sww::window::WindowAttributes;
 pub use sww::window::WindowSettings;
 pub use sww::Color;
+pub use sww::ColorSpace;
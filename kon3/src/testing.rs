@@ -0,0 +1,195 @@
+//! Helpers for rendering elements without a window and comparing them against stored images.
+//!
+//! Enabled by the `testing` feature, which the integration tests require:
+//! `cargo test --features testing`.
+
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::draw_frame_dirty;
+use crate::resources::Resources;
+use crate::DrawStats;
+use crate::Drawers;
+use crate::Element;
+use crate::LocationRect;
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use sww::image::Rgba;
+use sww::image::RgbaImage;
+use sww::wgpu;
+use sww::window::event::IntSize;
+use sww::window::RenderTarget;
+use sww::window::RenderTexture;
+use sww::window::RenderTextureSettings;
+
+/// Setting this environment variable writes the snapshots instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "KON3_UPDATE_SNAPSHOTS";
+
+struct Settings(IntSize);
+
+impl RenderTextureSettings for Settings {
+    fn size(&self) -> IntSize {
+        self.0
+    }
+
+    fn request_adapter_options(&self) -> wgpu::RequestAdapterOptions<'static, 'static> {
+        wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: true,
+            compatible_surface: None,
+        }
+    }
+}
+
+/// Renders frames one after another, keeping resources and cached drawings between them.
+pub struct Renderer {
+    rw: Arc<RenderTexture>,
+    resources: Resources,
+    drawers: Drawers,
+}
+
+impl Renderer {
+    pub fn new(size: IntSize) -> Self {
+        let rw =
+            Arc::new(RenderTexture::new(&Settings(size)).expect("failed to create render texture"));
+        let resources = Resources::new(Arc::clone(&rw) as _);
+
+        Self {
+            rw,
+            resources,
+            drawers: Drawers::default(),
+        }
+    }
+
+    pub fn render(&mut self, element: &(impl Element + ?Sized)) -> RgbaImage {
+        self.render_stats(element).0
+    }
+
+    /// Like [`Self::render`], also returning what it took to draw the element.
+    pub fn render_stats(&mut self, element: &(impl Element + ?Sized)) -> (RgbaImage, DrawStats) {
+        self.render_dirty(element, None)
+    }
+
+    /// Renders like [`draw_frame_dirty`], keeping the previous frame outside of `dirty_rects`.
+    pub fn render_dirty(
+        &mut self,
+        element: &(impl Element + ?Sized),
+        dirty_rects: Option<&[LocationRect]>,
+    ) -> (RgbaImage, DrawStats) {
+        let mut frame = self.rw.start_drawing();
+        draw_frame_dirty(
+            Arc::clone(&self.rw) as _,
+            &mut frame,
+            &mut self.drawers,
+            &self.resources,
+            element,
+            dirty_rects,
+        );
+        (frame.read_image(), self.drawers.stats())
+    }
+}
+
+pub fn render_element(element: &(impl Element + ?Sized), size: IntSize) -> RgbaImage {
+    Renderer::new(size).render(element)
+}
+
+/// Like [`render_element`], also returning what it took to draw the element.
+pub fn render_element_stats(
+    element: &(impl Element + ?Sized),
+    size: IntSize,
+) -> (RgbaImage, DrawStats) {
+    Renderer::new(size).render_stats(element)
+}
+
+/// A signaler for handling events outside of an app, along with the signals it sends.
+pub fn signaler() -> (Signaler, Receiver<Signal>) {
+    let (sender, receiver) = mpsc::channel();
+    (Signaler::new(sender), receiver)
+}
+
+/// Returns an image highlighting the differing pixels, if there are any.
+///
+/// Pixels are considered equal if none of their channels differ by more than `tolerance`.
+pub fn compare_images(
+    actual: &RgbaImage,
+    expected: &RgbaImage,
+    tolerance: u8,
+) -> Option<RgbaImage> {
+    if actual.dimensions() != expected.dimensions() {
+        return Some(actual.clone());
+    }
+
+    let mut differs = false;
+    let diff = RgbaImage::from_fn(actual.width(), actual.height(), |x, y| {
+        let actual = actual.get_pixel(x, y);
+        let expected = expected.get_pixel(x, y);
+
+        let is_same = (actual.0.iter().zip(expected.0))
+            .all(|(&actual, expected)| actual.abs_diff(expected) <= tolerance);
+
+        if is_same {
+            let [r, g, b, _] = expected.0;
+            let gray = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3 / 4) as u8;
+            Rgba([gray, gray, gray, 255])
+        } else {
+            differs = true;
+            Rgba([255, 0, 0, 255])
+        }
+    });
+
+    differs.then_some(diff)
+}
+
+/// Renders `element` and compares it against the PNG at `path`.
+///
+/// A missing snapshot fails the comparison, unless [`UPDATE_SNAPSHOTS_VAR`] is set. On mismatch,
+/// `<name>.actual.png` and `<name>.diff.png` are written next to the snapshot.
+pub fn assert_snapshot(
+    element: &(impl Element + ?Sized),
+    size: IntSize,
+    path: impl AsRef<Path>,
+    tolerance: u8,
+) {
+    let path = path.as_ref();
+    let actual = render_element(element, size);
+
+    if env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        actual.save(path).expect("failed to write snapshot");
+        return;
+    }
+
+    assert!(
+        path.exists(),
+        "snapshot {} is missing, set {UPDATE_SNAPSHOTS_VAR} to create it",
+        path.display(),
+    );
+
+    let expected = sww::image::open(path)
+        .expect("failed to read snapshot")
+        .into_rgba8();
+
+    if let Some(diff) = compare_images(&actual, &expected, tolerance) {
+        let actual_path = sibling_path(path, "actual");
+        let diff_path = sibling_path(path, "diff");
+
+        actual
+            .save(&actual_path)
+            .expect("failed to write actual image");
+        diff.save(&diff_path).expect("failed to write diff image");
+
+        panic!(
+            "rendered image differs from snapshot {}, see {} and {}",
+            path.display(),
+            actual_path.display(),
+            diff_path.display(),
+        );
+    }
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{stem}.{suffix}.png"))
+}
@@ -0,0 +1,125 @@
+use super::Font;
+use super::FontId;
+use crate::resources::Resource;
+use ab_glyph::Font as _;
+use ab_glyph::GlyphId;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use sww::image::RgbaImage;
+use sww::media::Atlas;
+use sww::media::Sampling;
+use sww::shaders::mesh::BindGroup1;
+use sww::shaders::mesh::BindGroupLayout1;
+use sww::shaders::mesh::Rectangle;
+use sww::vec2;
+use sww::wgpu;
+use sww::window::RenderTarget;
+use sww::Vec2;
+
+const PAGE_SIZE: u32 = 1024;
+
+#[derive(Clone)]
+pub struct AtlasGlyph {
+    pub bind_group: Arc<BindGroup1>,
+    /// Normalized rectangle of the glyph within its page.
+    pub texture_rect: Rectangle,
+    /// Offset of the glyph's top left corner from its origin in pixels.
+    pub offset: Vec2,
+    /// Size of the glyph in pixels.
+    pub size: Vec2,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct GlyphKey {
+    font: FontId,
+    glyph: GlyphId,
+    size: u32,
+}
+
+/// Rasterized glyphs of all fonts, packed into textures on demand.
+pub struct GlyphAtlas(Mutex<GlyphAtlasState>);
+
+struct GlyphAtlasState {
+    atlas: Atlas,
+    sampler: wgpu::Sampler,
+    /// Bind group of each page of `atlas`.
+    bind_groups: Vec<Arc<BindGroup1>>,
+    glyphs: HashMap<GlyphKey, Option<AtlasGlyph>>,
+}
+
+impl Resource for GlyphAtlas {
+    fn new(rw: &dyn RenderTarget) -> Self {
+        Self(Mutex::new(GlyphAtlasState {
+            atlas: Atlas::new(PAGE_SIZE).with_label("glyph atlas"),
+            sampler: Sampling::LINEAR.make_sampler(rw.device()),
+            bind_groups: Vec::new(),
+            glyphs: HashMap::new(),
+        }))
+    }
+}
+
+impl GlyphAtlas {
+    /// Returns `None` for glyphs with no outline, such as a space, or too large for a texture.
+    pub fn glyph(
+        &self,
+        rw: &dyn RenderTarget,
+        font: &Font,
+        glyph: GlyphId,
+        size: f32,
+    ) -> Option<AtlasGlyph> {
+        let key = GlyphKey {
+            font: font.id(),
+            glyph,
+            size: size.to_bits(),
+        };
+
+        let mut state = self.0.lock();
+        if let Some(res) = state.glyphs.get(&key) {
+            return res.clone();
+        }
+
+        let res = state.rasterize(rw, font, glyph, size);
+        state.glyphs.insert(key, res.clone());
+        res
+    }
+}
+
+impl GlyphAtlasState {
+    fn rasterize(
+        &mut self,
+        rw: &dyn RenderTarget,
+        font: &Font,
+        glyph: GlyphId,
+        size: f32,
+    ) -> Option<AtlasGlyph> {
+        let outlined = font.font().outline_glyph(glyph.with_scale(size))?;
+        let bounds = outlined.px_bounds();
+        let (width, height) = (bounds.width() as u32, bounds.height() as u32);
+
+        let mut pixels = [255, 255, 255, 0].repeat((width * height) as usize);
+        outlined.draw(|x, y, coverage| {
+            pixels[((y * width + x) * 4 + 3) as usize] =
+                (coverage.clamp(0., 1.) * 255.).round() as u8;
+        });
+
+        let image = RgbaImage::from_raw(width, height, pixels).unwrap();
+        let handle = self.atlas.add(rw, &image)?;
+        for page in &self.atlas.pages()[self.bind_groups.len()..] {
+            self.bind_groups.push(Arc::new(BindGroup1::from_bindings(
+                rw.device(),
+                BindGroupLayout1 {
+                    texture: page.texture_view(),
+                    texture_sampler: &self.sampler,
+                },
+            )));
+        }
+
+        Some(AtlasGlyph {
+            bind_group: Arc::clone(&self.bind_groups[handle.page]),
+            texture_rect: handle.texture_rect,
+            offset: vec2(bounds.min.x, bounds.min.y),
+            size: handle.size,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use super::GlyphAtlas;
+use super::TextLayout;
+use crate::drawer::resources::Resources;
+use crate::drawer::DrawPass;
+use crate::resources::mesh::NoGlobalTransform;
+use crate::resources::mesh::UnitSquareTopLeft;
+use crate::LocationRect;
+use crate::MeshDrawingInfo;
+use sww::shaders::mesh::Transform;
+use sww::Color;
+use sww::Vec2;
+
+impl TextLayout {
+    /// Draws the text with its top left corner at `origin`, in pixels relative to `location`.
+    pub fn draw(
+        &self,
+        pass: &mut DrawPass,
+        resources: &Resources,
+        location: LocationRect,
+        origin: Vec2,
+        color: Color,
+    ) {
+        let atlas = resources.get::<GlyphAtlas>();
+        let mesh = resources.get::<UnitSquareTopLeft>();
+        let bind_group0 = resources.get::<NoGlobalTransform>();
+
+        for glyph in self.glyphs() {
+            let Some(atlas_glyph) =
+                atlas.glyph(resources.rw(), self.font(), glyph.id, self.font_size())
+            else {
+                continue;
+            };
+
+            let top_left = (origin + glyph.position).round() + atlas_glyph.offset;
+            let rect = location.pixel_subrect(top_left, atlas_glyph.size).rect();
+
+            // the shader samples textures bottom-up, while `y` points down here
+            let texture_rect = atlas_glyph.texture_rect.flip_vertically();
+
+            pass.mesh().draw(
+                &MeshDrawingInfo {
+                    mesh,
+                    bind_group0,
+                    bind_group1: atlas_glyph.bind_group,
+                },
+                Transform::new_scale(rect.top_left, rect.size, color, texture_rect),
+            );
+        }
+    }
+}
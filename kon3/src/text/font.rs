@@ -0,0 +1,60 @@
+use ab_glyph::FontArc;
+use ab_glyph::InvalidFont;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::OnceLock;
+
+static DEFAULT_FONT_BYTES: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontId(u64);
+
+impl FontId {
+    fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A TTF/OTF font. Cloning is cheap, clones share glyphs in the atlas.
+#[derive(Clone)]
+pub struct Font {
+    id: FontId,
+    font: FontArc,
+}
+
+impl Font {
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, InvalidFont> {
+        FontArc::try_from_vec(bytes).map(Self::new)
+    }
+
+    pub fn from_static(bytes: &'static [u8]) -> Result<Self, InvalidFont> {
+        FontArc::try_from_slice(bytes).map(Self::new)
+    }
+
+    fn new(font: FontArc) -> Self {
+        Self {
+            id: FontId::next(),
+            font,
+        }
+    }
+
+    pub const fn id(&self) -> FontId {
+        self.id
+    }
+
+    pub const fn font(&self) -> &FontArc {
+        &self.font
+    }
+}
+
+/// DejaVu Sans, bundled with the crate.
+impl Default for Font {
+    fn default() -> Self {
+        static DEFAULT: OnceLock<Font> = OnceLock::new();
+
+        DEFAULT
+            .get_or_init(|| Self::from_static(DEFAULT_FONT_BYTES).unwrap())
+            .clone()
+    }
+}
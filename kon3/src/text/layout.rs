@@ -0,0 +1,103 @@
+use super::Font;
+use ab_glyph::Font as _;
+use ab_glyph::GlyphId;
+use ab_glyph::PxScale;
+use ab_glyph::ScaleFont;
+use sww::vec2;
+use sww::Vec2;
+
+#[derive(Clone, Copy)]
+pub struct LayoutGlyph {
+    pub id: GlyphId,
+    /// Byte index of the glyph's character in the text.
+    pub index: usize,
+    /// Position of the glyph's origin on the baseline, in pixels relative to the text's top left corner.
+    pub position: Vec2,
+}
+
+pub struct TextLayout {
+    font: Font,
+    font_size: f32,
+    glyphs: Vec<LayoutGlyph>,
+    len: usize,
+    size: Vec2,
+}
+
+impl TextLayout {
+    pub fn new(font: &Font, font_size: f32, text: &str) -> Self {
+        let scaled_font = font.font().as_scaled(PxScale::from(font_size));
+        let line_height = scaled_font.height() + scaled_font.line_gap();
+
+        let mut glyphs = Vec::with_capacity(text.len());
+        let mut caret = vec2(0., scaled_font.ascent());
+        let mut width = 0_f32;
+        let mut previous = None;
+
+        for (index, c) in text.char_indices() {
+            if c == '\n' {
+                width = width.max(caret.x);
+                caret = vec2(0., caret.y + line_height);
+                previous = None;
+                continue;
+            }
+
+            let id = scaled_font.glyph_id(c);
+            if let Some(previous) = previous {
+                caret.x += scaled_font.kern(previous, id);
+            }
+
+            glyphs.push(LayoutGlyph {
+                id,
+                index,
+                position: caret,
+            });
+
+            caret.x += scaled_font.h_advance(id);
+            previous = Some(id);
+        }
+
+        let width = width.max(caret.x);
+        let height = caret.y - scaled_font.ascent() + scaled_font.height();
+
+        Self {
+            font: font.clone(),
+            font_size,
+            glyphs,
+            len: text.len(),
+            size: vec2(width, height),
+        }
+    }
+
+    pub const fn font(&self) -> &Font {
+        &self.font
+    }
+
+    pub const fn font_size(&self) -> f32 {
+        self.font_size
+    }
+
+    pub fn glyphs(&self) -> &[LayoutGlyph] {
+        &self.glyphs
+    }
+
+    /// Horizontal position of a caret placed before the byte `index`, in pixels.
+    pub fn caret_x(&self, index: usize) -> f32 {
+        (self.glyphs.iter())
+            .find(|glyph| glyph.index >= index)
+            .map_or(self.size.x, |glyph| glyph.position.x)
+    }
+
+    /// Byte index of the caret position closest to `x`, in pixels.
+    pub fn hit_index(&self, x: f32) -> usize {
+        (self.glyphs.iter())
+            .map(|glyph| (glyph.index, glyph.position.x))
+            .chain([(self.len, self.size.x)])
+            .min_by(|(_, a), (_, b)| (a - x).abs().total_cmp(&(b - x).abs()))
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Size of the text's bounding box in pixels.
+    pub const fn size(&self) -> Vec2 {
+        self.size
+    }
+}
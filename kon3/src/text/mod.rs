@@ -0,0 +1,28 @@
+mod atlas;
+mod draw;
+mod font;
+mod layout;
+
+pub use ab_glyph::InvalidFont;
+pub use atlas::*;
+pub use font::*;
+pub use layout::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+impl Alignment {
+    /// Offset of an item of `size` aligned within `available` space.
+    pub fn offset(self, size: f32, available: f32) -> f32 {
+        match self {
+            Self::Start => 0.,
+            Self::Center => (available - size) / 2.,
+            Self::End => available - size,
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use super::ContainsShared;
+use super::ValueSource;
+use super::ValueSourceBorrow;
+use crate::app::Signal;
+use crate::app::Signaler;
+use crate::shared::SharedAddr;
+use crate::Event;
+use crate::EventResult;
+use crate::HandleEvent;
+use crate::LocationRect;
+use parking_lot::MappedMutexGuard;
+use parking_lot::Mutex;
+use parking_lot::MutexGuard;
+use std::borrow::Borrow;
+use std::time::Duration;
+use std::time::Instant;
+use sww::Color;
+use sww::Vec2;
+
+/// Values that can be animated between.
+pub trait Interpolate: Copy + PartialEq {
+    /// `t` is `0` for `self` and `1` for `other`.
+    fn interpolate(self, other: Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+/// Maps the elapsed part of an animation to how far its value has moved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// `t` is in `0..=1`.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1. - (1. - t).powi(3),
+            Self::EaseInOut if t < 0.5 => 4. * t * t * t,
+            Self::EaseInOut => 1. - (2. - 2. * t).powi(3) / 2.,
+        }
+    }
+}
+
+struct Transition<T> {
+    from: T,
+    start: Instant,
+}
+
+struct AnimationState<T> {
+    current: T,
+    target: T,
+    transition: Option<Transition<T>>,
+}
+
+/// Follows the value of `source`, moving to each new value over `duration` instead of jumping.
+///
+/// Runs on [`Event::AnimationFrame`], which is only sent while some animation is in progress.
+pub struct Animated<T, Src> {
+    source: Src,
+    duration: Duration,
+    easing: Easing,
+    state: Mutex<Option<AnimationState<T>>>,
+}
+
+impl<T, Src> Animated<T, Src> {
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+}
+
+impl<T: Interpolate, Src: ValueSourceBorrow<T>> Animated<T, Src> {
+    fn target(&self) -> T {
+        *(*self.source.value()).borrow()
+    }
+
+    fn progress(&self, start: Instant, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.;
+        }
+        let elapsed = now.saturating_duration_since(start);
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+    }
+}
+
+impl<T: Interpolate, Src: ValueSourceBorrow<T>> ValueSource for Animated<T, Src> {
+    type Value<'s>
+        = MappedMutexGuard<'s, T>
+    where
+        Self: 's;
+
+    fn value(&self) -> Self::Value<'_> {
+        MutexGuard::map(self.state.lock(), |state| {
+            let state = state.get_or_insert_with(|| {
+                let target = self.target();
+                AnimationState {
+                    current: target,
+                    target,
+                    transition: None,
+                }
+            });
+            &mut state.current
+        })
+    }
+}
+
+impl<T, Src: ContainsShared> ContainsShared for Animated<T, Src> {
+    fn contains_shared(&self, addr: SharedAddr) -> bool {
+        self.source.contains_shared(addr)
+    }
+}
+
+impl<T: Interpolate, Src: ValueSourceBorrow<T>> HandleEvent for Animated<T, Src> {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
+        self.source.handle_event(signaler, event, location)?;
+
+        let target = self.target();
+        let mut state = self.state.lock();
+        let state = state.get_or_insert_with(|| AnimationState {
+            current: target,
+            target,
+            transition: None,
+        });
+
+        if target != state.target {
+            state.target = target;
+            state.transition = Some(Transition {
+                from: state.current,
+                start: Instant::now(),
+            });
+            signaler.send(Signal::AnimationFrame);
+        }
+
+        if let (Event::AnimationFrame(now), Some(transition)) = (event, &state.transition) {
+            let progress = self.progress(transition.start, *now);
+            state.current = (transition.from).interpolate(target, self.easing.apply(progress));
+            signaler.send(Signal::Redraw);
+
+            if progress < 1. {
+                signaler.send(Signal::AnimationFrame);
+            } else {
+                state.transition = None;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub const fn animated<T: Interpolate, Src: ValueSourceBorrow<T>>(
+    ra_fixture_source: Src,
+    ra_fixture_duration: Duration,
+) -> Animated<T, Src> {
+    Animated {
+        source: ra_fixture_source,
+        duration: ra_fixture_duration,
+        easing: Easing::EaseInOut,
+        state: Mutex::new(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::rect;
+    use crate::shared::Shared;
+    use std::sync::mpsc;
+    use sww::window::event::IntSize;
+
+    const DURATION: Duration = Duration::from_millis(100);
+
+    fn location() -> LocationRect {
+        LocationRect::new(IntSize::new(1, 1))
+    }
+
+    #[test]
+    fn easings_start_and_end_in_place() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.), 0.);
+            assert_eq!(easing.apply(1.), 1.);
+        }
+    }
+
+    #[test]
+    fn easings_midpoints() {
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.0625);
+        assert_eq!(Easing::EaseInOut.apply(0.75), 0.9375);
+    }
+
+    #[test]
+    fn animated_reaches_target_after_duration() {
+        let (sender, signals) = mpsc::channel();
+        let signaler = Signaler::new(sender);
+        let source = Shared::new(0_f32);
+        let animated = animated(source.clone(), DURATION).easing(Easing::Linear);
+        assert_eq!(*animated.value(), 0.);
+
+        *source.write(&signaler) = 10.;
+        let event = Event::SharedUpdated(source.addr());
+        _ = animated.handle_event(&signaler, &event, location());
+        assert_eq!(*animated.value(), 0.);
+        assert!(signals
+            .try_iter()
+            .any(|signal| signal == Signal::AnimationFrame));
+
+        let event = Event::AnimationFrame(Instant::now() + 2 * DURATION);
+        _ = animated.handle_event(&signaler, &event, location());
+        assert_eq!(*animated.value(), 10.);
+        assert!(!signals
+            .try_iter()
+            .any(|signal| signal == Signal::AnimationFrame));
+    }
+
+    #[test]
+    fn rect_animates_its_color() {
+        let (sender, signals) = mpsc::channel();
+        let signaler = Signaler::new(sender);
+        let source = Shared::new(Color::BLACK);
+        let element = rect(animated(source.clone(), DURATION));
+        // starts following the source
+        _ = element.handle_event(&signaler, &Event::FocusChanged(None), location());
+
+        *source.write(&signaler) = Color::WHITE;
+        let event = Event::SharedUpdated(source.addr());
+        _ = element.handle_event(&signaler, &event, location());
+        assert!(signals
+            .try_iter()
+            .any(|signal| signal == Signal::AnimationFrame));
+
+        let event = Event::AnimationFrame(Instant::now() + 2 * DURATION);
+        _ = element.handle_event(&signaler, &event, location());
+        assert!(signals.try_iter().any(|signal| signal == Signal::Redraw));
+    }
+}
@@ -8,6 +8,7 @@ use crate::ContainsShared;
 use crate::Event;
 use crate::EventResult;
 use crate::HandleEvent;
+use crate::LocationRect;
 use std::borrow::Borrow;
 
 pub struct Concat<Src> {
@@ -42,9 +43,14 @@ impl<Src: ContainsShared> ContainsShared for Concat<Src> {
 }
 
 impl<Src: HandleEvent> HandleEvent for Concat<Src> {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
         self.cache.reset();
-        self.sources.handle_event(signaler, event)
+        self.sources.handle_event(signaler, event, location)
     }
 }
 
@@ -1,8 +1,10 @@
+mod animated;
 mod cache;
 mod concat;
 mod source;
 mod strfy;
 
+pub use animated::*;
 pub use cache::*;
 pub use concat::*;
 pub use source::*;
@@ -6,6 +6,7 @@ use crate::ContainsShared;
 use crate::Event;
 use crate::EventResult;
 use crate::HandleEvent;
+use crate::LocationRect;
 use sww::Color;
 use sww::Mat2;
 use sww::Vec2;
@@ -45,7 +46,12 @@ impl<T: AutoValueSource + ?Sized> ContainsShared for T {
 }
 
 impl<T: AutoValueSource + ?Sized> HandleEvent for T {
-    fn handle_event(&self, _signal_sender: &Signaler, _event: &Event) -> EventResult {
+    fn handle_event(
+        &self,
+        _signal_sender: &Signaler,
+        _event: &Event,
+        _location: LocationRect,
+    ) -> EventResult {
         Ok(())
     }
 }
@@ -5,8 +5,10 @@ use crate::shared::SharedWriteGuard;
 use crate::Event;
 use crate::EventResult;
 use crate::HandleEvent;
+use crate::LocationRect;
 use crate::Shared;
 use std::borrow::Borrow;
+use std::borrow::BorrowMut;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
@@ -52,7 +54,12 @@ impl<T: ?Sized> ContainsShared for Shared<T> {
 }
 
 impl<T: ?Sized> HandleEvent for Shared<T> {
-    fn handle_event(&self, _signal_sender: &Signaler, _event: &Event) -> EventResult {
+    fn handle_event(
+        &self,
+        _signal_sender: &Signaler,
+        _event: &Event,
+        _location: LocationRect,
+    ) -> EventResult {
         Ok(())
     }
 }
@@ -76,3 +83,16 @@ impl<V: ?Sized, T: for<'s> ValueSource<Value<'s>: Deref<Target: Borrow<V>>> + ?S
     ValueSourceBorrow<V> for T
 {
 }
+
+pub trait ValueSourceBorrowMut<V: ?Sized>:
+    ValueSourceBorrow<V> + for<'s> ValueSourceMut<ValueMut<'s>: DerefMut<Target: BorrowMut<V>>>
+{
+}
+impl<
+        V: ?Sized,
+        T: ValueSourceBorrow<V>
+            + for<'s> ValueSourceMut<ValueMut<'s>: DerefMut<Target: BorrowMut<V>>>
+            + ?Sized,
+    > ValueSourceBorrowMut<V> for T
+{
+}
@@ -7,6 +7,7 @@ use crate::shared::SharedAddr;
 use crate::Event;
 use crate::EventResult;
 use crate::HandleEvent;
+use crate::LocationRect;
 use std::ops::Deref;
 
 pub struct Strfy<Src> {
@@ -33,9 +34,14 @@ impl<Src: ContainsShared> ContainsShared for Strfy<Src> {
 }
 
 impl<Src: HandleEvent> HandleEvent for Strfy<Src> {
-    fn handle_event(&self, signaler: &Signaler, event: &Event) -> EventResult {
+    fn handle_event(
+        &self,
+        signaler: &Signaler,
+        event: &Event,
+        location: LocationRect,
+    ) -> EventResult {
         self.cache.reset();
-        self.source.handle_event(signaler, event)
+        self.source.handle_event(signaler, event, location)
     }
 }
 
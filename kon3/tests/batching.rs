@@ -0,0 +1,45 @@
+use kon3::prelude::*;
+use kon3::testing::render_element_stats;
+use sww::image::Rgba;
+use sww::image::RgbaImage;
+use sww::window::event::IntSize;
+
+const SIZE: IntSize = IntSize::new(64, 64);
+
+fn green_image() -> ImageData {
+    ImageData::new(RgbaImage::from_pixel(1, 1, Rgba([0, 255, 0, 255])))
+}
+
+#[test]
+fn consecutive_rects_share_a_batch() {
+    let element = column((rect(Color::RED), rect(Color::BLUE)));
+    let (_, stats) = render_element_stats(&element, SIZE);
+
+    assert_eq!(stats.batches, 1);
+    assert_eq!(stats.draw_calls, 1);
+    assert_eq!(stats.instances, 2);
+}
+
+#[test]
+fn different_bind_groups_split_batches() {
+    let element = column((rect(Color::RED), image(green_image()), rect(Color::BLUE)));
+    let (_, stats) = render_element_stats(&element, SIZE);
+
+    assert_eq!(stats.batches, 3);
+    assert_eq!(stats.draw_calls, 3);
+    assert_eq!(stats.instances, 3);
+}
+
+#[test]
+fn batching_keeps_draw_order() {
+    let element = layers((
+        rect(Color::RED),
+        image(green_image()),
+        column((rect(Color::BLUE), rect(Color::TRANSPARENT))),
+    ));
+    let (image, stats) = render_element_stats(&element, SIZE);
+
+    assert_eq!(stats.batches, 3);
+    assert_eq!(image.get_pixel(32, 16), &Rgba([0, 0, 255, 255]));
+    assert_eq!(image.get_pixel(32, 48), &Rgba([0, 255, 0, 255]));
+}
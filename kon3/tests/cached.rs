@@ -0,0 +1,62 @@
+use kon3::prelude::*;
+use kon3::testing::signaler;
+use kon3::testing::Renderer;
+use sww::image::Rgba;
+use sww::window::event::IntSize;
+
+const SIZE: IntSize = IntSize::new(64, 64);
+
+#[test]
+fn nested_cached_redraws_on_inner_change() {
+    let color = Shared::new(Color::RED);
+    let element = cached(column((
+        cached(rounded_rect(color.clone())),
+        rect(Color::BLUE),
+    )));
+    let (signaler, _signals) = signaler();
+    let mut renderer = Renderer::new(SIZE);
+
+    let image = renderer.render(&element);
+    assert_eq!(image.get_pixel(32, 16), &Rgba([255, 0, 0, 255]));
+
+    *color.write(&signaler) = Color::GREEN;
+    let event = Event::SharedUpdated(color.addr());
+    _ = dispatch_event(&element, &signaler, &event, LocationRect::new(SIZE));
+
+    let image = renderer.render(&element);
+    assert_eq!(image.get_pixel(32, 16), &Rgba([0, 255, 0, 255]));
+    assert_eq!(image.get_pixel(32, 48), &Rgba([0, 0, 255, 255]));
+}
+
+#[test]
+fn dirty_frames_keep_the_rest_of_the_previous_frame() {
+    let color = Shared::new(Color::RED);
+    let background = Shared::new(Color::BLUE);
+    let element = column((
+        cached(rounded_rect(color.clone())),
+        rounded_rect(background.clone()),
+    ));
+    let (signaler, _signals) = signaler();
+    let mut renderer = Renderer::new(SIZE);
+    renderer.render(&element);
+
+    let mut redraw_with = |value: Color| {
+        *color.write(&signaler) = value;
+        let event = Event::SharedUpdated(color.addr());
+        _ = dispatch_event(&element, &signaler, &event, LocationRect::new(SIZE));
+
+        let dirty_rects = signaler.take_dirty_rects();
+        assert_eq!(dirty_rects.as_ref().map(Vec::len), Some(1));
+        renderer.render_dirty(&element, dirty_rects.as_deref()).0
+    };
+
+    let image = redraw_with(Color::GREEN);
+    assert_eq!(image.get_pixel(32, 16), &Rgba([0, 255, 0, 255]));
+
+    // isn't redrawn, as nothing requested it
+    *background.write(&signaler) = Color::RED;
+
+    let image = redraw_with(Color::WHITE);
+    assert_eq!(image.get_pixel(32, 16), &Rgba([255, 255, 255, 255]));
+    assert_eq!(image.get_pixel(32, 48), &Rgba([0, 0, 255, 255]));
+}
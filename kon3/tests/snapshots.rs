@@ -0,0 +1,62 @@
+use kon3::prelude::*;
+use kon3::testing::assert_snapshot;
+use std::path::PathBuf;
+use sww::window::event::IntSize;
+
+const SIZE: IntSize = IntSize::new(64, 64);
+const TOLERANCE: u8 = 2;
+
+fn snapshot(name: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots", name]
+        .iter()
+        .collect::<PathBuf>()
+        .with_extension("png")
+}
+
+#[test]
+fn rect_fills_location() {
+    assert_snapshot(&rect(Color::RED), SIZE, snapshot("rect"), TOLERANCE);
+}
+
+#[test]
+fn label_draws_text() {
+    let element = label("kon3").size(24.);
+    assert_snapshot(&element, SIZE, snapshot("label"), TOLERANCE);
+}
+
+#[test]
+fn column_splits_vertically() {
+    let element = column((rect(Color::RED), rect(Color::GREEN), rect(Color::BLUE)));
+    assert_snapshot(&element, SIZE, snapshot("column"), TOLERANCE);
+}
+
+#[test]
+fn line_splits_horizontally() {
+    let element = line((rect(Color::RED), rect(Color::GREEN), rect(Color::BLUE)));
+    assert_snapshot(&element, SIZE, snapshot("line"), TOLERANCE);
+}
+
+#[test]
+fn layers_draw_in_order() {
+    let element = layers((
+        rect(Color::BLUE),
+        column((rect(Color::TRANSPARENT), rect(Color::YELLOW))),
+    ));
+    assert_snapshot(&element, SIZE, snapshot("layers"), TOLERANCE);
+}
+
+#[test]
+fn text_input_clips_long_text() {
+    let text = Shared::new("a text that is longer than the field".to_owned());
+    let element = line((text_input(text), rect(Color::BLUE)));
+    assert_snapshot(&element, SIZE, snapshot("text_input"), TOLERANCE);
+}
+
+#[test]
+fn slider_handles_empty_range_and_narrow_location() {
+    let element = column((
+        slider(1_f32, 1. ..=1.),
+        line((fixed(slider(0.5_f32, 0. ..=1.), 8.), rect(Color::BLUE))),
+    ));
+    assert_snapshot(&element, SIZE, snapshot("slider"), TOLERANCE);
+}
@@ -0,0 +1,38 @@
+use kon3::prelude::*;
+use kon3::testing::signaler;
+use sww::dvec2;
+use sww::window::event::IntSize;
+use sww::window::event::MouseButton;
+
+const SIZE: IntSize = IntSize::new(200, 32);
+
+#[test]
+fn typing_after_the_text_was_shortened() {
+    let text = Shared::new("hello".to_owned());
+    let element = text_input(text.clone());
+    let (signaler, _signals) = signaler();
+    let location = LocationRect::new(SIZE);
+
+    // puts the caret after the text
+    let point = LocationPoint::from_window_point(dvec2(190., 16.), SIZE);
+    let button = MouseButton::Left;
+    _ = dispatch_event(
+        &element,
+        &signaler,
+        &Event::PointerDown { point, button },
+        location,
+    );
+    _ = dispatch_event(
+        &element,
+        &signaler,
+        &Event::PointerUp { point, button },
+        location,
+    );
+    assert!(signaler.is_focused(element.id()));
+
+    // changed elsewhere, before its update is handled
+    *text.write(&signaler) = "h".to_owned();
+    _ = dispatch_event(&element, &signaler, &Event::Text("!".to_owned()), location);
+
+    assert_eq!(*text.read(), "h!");
+}
@@ -7,8 +7,7 @@ fn main() {
 
     let root_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
 
-    #[allow(clippy::single_element_loop)]
-    for file_name in ["mesh"] {
+    for file_name in ["mesh", "sdf"] {
         let input_path = format!("src/shaders/{file_name}/shader.wgsl");
         let output_path = format!("{output_dir}/{file_name}.rs");
         let input_absolute_path = format!("{root_dir}/{input_path}");
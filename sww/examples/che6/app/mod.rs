@@ -49,14 +49,10 @@ impl EventHandler {
     fn draw(&self, frame: &mut Frame) {
         let (commands, surface) = frame.commands_surface();
         let mut render_pass = (commands.encoder()).begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: surface.view(),
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                },
-            })],
+            color_attachments: &[Some(
+                surface.color_attachment(wgpu::LoadOp::Clear(wgpu::Color::BLACK)),
+            )],
+            depth_stencil_attachment: surface.depth_stencil_attachment(),
             ..Default::default()
         });
 
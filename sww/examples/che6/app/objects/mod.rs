@@ -10,6 +10,7 @@ use sww::buffers::MutVecBuffer;
 use sww::media::read_image;
 use sww::shaders::mesh::Transform;
 use sww::vec2;
+use sww::window::RenderTarget;
 use sww::window::RenderWindow;
 use sww::Mat2;
 
@@ -36,7 +37,7 @@ impl Objects {
 
         let pieces = {
             let sheet = PiecesSheet::new(
-                rw,
+                &**rw,
                 read_image(io::Cursor::new(include_bytes!("pieces.png"))).unwrap(),
             );
             let transforms = make_piece_transforms(rw, &sheet);
@@ -76,7 +77,7 @@ impl Objects {
 }
 
 fn make_piece_transforms(rw: &Arc<RenderWindow>, sheet: &PiecesSheet) -> MutVecBuffer<Transform> {
-    let mut piece_transforms = MutVecBuffer::default_vertex(Arc::clone(rw));
+    let mut piece_transforms = MutVecBuffer::default_vertex(Arc::clone(rw) as _);
 
     for (y, piece_color) in [(-3, PieceColor::White), (3 - 1, PieceColor::Black)] {
         for x in -4..4 {
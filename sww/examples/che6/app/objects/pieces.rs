@@ -7,11 +7,16 @@ use std::sync::Arc;
 use sww::buffers::Binding;
 use sww::buffers::MutBuffer;
 use sww::buffers::MutVecBuffer;
+use sww::media::Sampling;
 use sww::shaders;
 use sww::shaders::mesh::Transform;
+use sww::window::RenderTarget;
 use sww::window::RenderWindow;
 use sww::Vec2;
 
+/// Pieces are drawn over tiles by depth, independent of draw order.
+const PIECE_Z: f32 = 0.5;
+
 pub fn make_piece_transform(
     sheet: &PiecesSheet,
     translation: Vec2,
@@ -24,6 +29,7 @@ pub fn make_piece_transform(
         texture_rect,
         ..Default::default()
     }
+    .with_z(PIECE_Z)
 }
 
 pub struct Pieces {
@@ -55,6 +61,7 @@ impl Pieces {
             rw.device(),
             shaders::mesh::BindGroupLayout1 {
                 texture: sheet.texture_view(),
+                texture_sampler: &Sampling::LINEAR.make_sampler(rw.device()),
             },
         );
 
@@ -6,6 +6,7 @@ use sww::buffers::MutVecBuffer;
 use sww::media;
 use sww::shaders;
 use sww::shaders::mesh::Transform;
+use sww::window::RenderTarget;
 use sww::window::RenderWindow;
 use sww::Color;
 
@@ -36,8 +37,8 @@ pub fn make_white_black_tranforms(
     }
 
     (
-        MutVecBuffer::new_vertex(Arc::clone(rw), white),
-        MutVecBuffer::new_vertex(Arc::clone(rw), black),
+        MutVecBuffer::new_vertex(Arc::clone(rw) as _, white),
+        MutVecBuffer::new_vertex(Arc::clone(rw) as _, black),
     )
 }
 
@@ -61,6 +62,7 @@ impl Tiles {
                 rw.device(),
                 shaders::mesh::BindGroupLayout1 {
                     texture: &default_texture_view,
+                    texture_sampler: &media::Sampling::NEAREST.make_sampler(rw.device()),
                 },
             )
         };
@@ -7,6 +7,7 @@ use sww::buffers::MutBuffer;
 use sww::buffers::MutVecBuffer;
 use sww::shaders;
 use sww::shaders::mesh::Transform;
+use sww::window::RenderTarget;
 use sww::window::RenderWindow;
 use sww::Color;
 use sww::Vec2;
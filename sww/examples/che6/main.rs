@@ -10,6 +10,10 @@ impl WindowSettings for Settings {
     fn window_attributes(&self) -> WindowAttributes {
         window_attributes("che6", 400, 200)
     }
+
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat> {
+        Some(wgpu::TextureFormat::Depth24PlusStencil8)
+    }
 }
 
 fn main() {
@@ -0,0 +1,129 @@
+use crate::window::RenderTarget;
+use bytemuck::NoUninit;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::Arc;
+
+const INITIAL_CAPACITY: usize = 256;
+
+/// A vertex buffer holding the instances of all draw calls of a frame, each drawn from its own
+/// range of it. The buffer is reused by later frames and grows geometrically.
+pub struct InstanceArena<T> {
+    rw: Arc<dyn RenderTarget>,
+    buffer: Arc<wgpu::Buffer>,
+    staging: Vec<T>,
+    /// Number of instances at the start of `staging` which have been written to the buffer.
+    uploaded: usize,
+}
+
+/// Instances of type `T` pushed into an [`InstanceArena`], valid until the arena is cleared.
+pub struct InstanceSlice<T> {
+    buffer: Arc<wgpu::Buffer>,
+    offset: wgpu::BufferAddress,
+    size: wgpu::BufferAddress,
+    count: u32,
+    instance: PhantomData<T>,
+}
+
+impl<T> Clone for InstanceSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: Arc::clone(&self.buffer),
+            instance: PhantomData,
+            ..*self
+        }
+    }
+}
+
+impl<T> InstanceSlice<T> {
+    pub fn buffer_slice(&self) -> wgpu::BufferSlice<'_> {
+        self.buffer.slice(self.offset..self.offset + self.size)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl<T: NoUninit> InstanceArena<T> {
+    pub fn new(rw: Arc<dyn RenderTarget>) -> Self {
+        let buffer = create_buffer::<T>(&rw, INITIAL_CAPACITY);
+
+        Self {
+            rw,
+            buffer,
+            staging: Vec::new(),
+            uploaded: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.staging.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.size() as usize / mem::size_of::<T>()
+    }
+
+    /// Appends `instances` after the ones of earlier draw calls. They are only written to the
+    /// buffer by [`Self::upload`].
+    pub fn push(&mut self, instances: &[T]) -> InstanceSlice<T> {
+        let start = self.staging.len();
+        let end = start + instances.len();
+        if end > self.capacity() {
+            self.grow(end);
+        }
+        self.staging.extend_from_slice(instances);
+
+        let instance_size = mem::size_of::<T>() as wgpu::BufferAddress;
+        InstanceSlice {
+            buffer: Arc::clone(&self.buffer),
+            offset: start as wgpu::BufferAddress * instance_size,
+            size: instances.len() as wgpu::BufferAddress * instance_size,
+            count: instances.len() as _,
+            instance: PhantomData,
+        }
+    }
+
+    /// Writes the instances pushed since the last upload, which has to happen before the frame
+    /// is submitted.
+    pub fn upload(&mut self) {
+        if self.uploaded < self.staging.len() {
+            let offset = (self.uploaded * mem::size_of::<T>()) as wgpu::BufferAddress;
+            (self.rw.queue()).write_buffer(
+                &self.buffer,
+                offset,
+                bytemuck::cast_slice(&self.staging[self.uploaded..]),
+            );
+            self.uploaded = self.staging.len();
+        }
+    }
+
+    /// Starts over for a new frame, keeping the buffer. Draw calls of the previous frame must
+    /// have been submitted.
+    pub fn clear(&mut self) {
+        self.staging.clear();
+        self.uploaded = 0;
+    }
+
+    fn grow(&mut self, min_capacity: usize) {
+        // draw calls recorded so far keep using the old buffer, so it needs their instances
+        self.upload();
+
+        let capacity = min_capacity.max(self.capacity() * 2);
+        self.buffer = create_buffer::<T>(&self.rw, capacity);
+    }
+}
+
+fn create_buffer<T>(rw: &Arc<dyn RenderTarget>, capacity: usize) -> Arc<wgpu::Buffer> {
+    Arc::new(rw.device().create_buffer(&wgpu::BufferDescriptor {
+        label: Some("InstanceArena"),
+        size: (capacity * mem::size_of::<T>()) as _,
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    }))
+}
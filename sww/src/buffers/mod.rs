@@ -1,9 +1,11 @@
+mod arena;
 mod bytes;
 mod extensions;
 mod r#mut;
 mod mut_vec;
 mod vec;
 
+pub use arena::*;
 pub use bytes::*;
 pub use extensions::*;
 pub use mut_vec::*;
@@ -1,5 +1,5 @@
 use crate::buffers::create_buffer_partially_init;
-use crate::window::RenderWindow;
+use crate::window::RenderTarget;
 use parking_lot::Mutex;
 use std::mem;
 use std::ops::Index;
@@ -14,7 +14,7 @@ struct Cache {
 }
 
 pub struct MutVecBuffer<T> {
-    rw: Arc<RenderWindow>,
+    rw: Arc<dyn RenderTarget>,
     values: Vec<T>,
 
     usage: wgpu::BufferUsages,
@@ -22,7 +22,7 @@ pub struct MutVecBuffer<T> {
 }
 
 impl<T> MutVecBuffer<T> {
-    pub fn new(rw: Arc<RenderWindow>, values: Vec<T>, usage: wgpu::BufferUsages) -> Self {
+    pub fn new(rw: Arc<dyn RenderTarget>, values: Vec<T>, usage: wgpu::BufferUsages) -> Self {
         let usage = usage | wgpu::BufferUsages::COPY_DST;
         let cache = Mutex::default();
 
@@ -35,15 +35,15 @@ impl<T> MutVecBuffer<T> {
         }
     }
 
-    pub fn default(rw: Arc<RenderWindow>, usage: wgpu::BufferUsages) -> Self {
+    pub fn default(rw: Arc<dyn RenderTarget>, usage: wgpu::BufferUsages) -> Self {
         Self::new(rw, Vec::default(), usage)
     }
 
-    pub fn new_vertex(rw: Arc<RenderWindow>, values: Vec<T>) -> Self {
+    pub fn new_vertex(rw: Arc<dyn RenderTarget>, values: Vec<T>) -> Self {
         Self::new(rw, values, wgpu::BufferUsages::VERTEX)
     }
 
-    pub fn default_vertex(rw: Arc<RenderWindow>) -> Self {
+    pub fn default_vertex(rw: Arc<dyn RenderTarget>) -> Self {
         Self::default(rw, wgpu::BufferUsages::VERTEX)
     }
 
@@ -1,6 +1,35 @@
 use glam::Vec4;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// How colour values are stored in a texture or presented on a surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Values are stored and read as is. Since [`Color`]s are linear, a surface in this space
+    /// shows them too dark; it suits data that isn't displayed directly.
+    Linear,
+    /// Values are converted between linear and sRGB by the GPU on every read and write, so
+    /// linear [`Color`]s are displayed as intended.
+    #[default]
+    Srgb,
+}
+
+impl ColorSpace {
+    /// Returns the variant of an 8-bit RGBA or BGRA `format` for this colour space.
+    pub fn apply(self, format: wgpu::TextureFormat) -> wgpu::TextureFormat {
+        match self {
+            Self::Linear => format.remove_srgb_suffix(),
+            Self::Srgb => format.add_srgb_suffix(),
+        }
+    }
+}
+
+/// A colour with linear components, the same space that shaders and blending work in.
+///
+/// Use [`Color::hex`], [`Color::from_srgba8`] and the HSL/HSV constructors for colours picked
+/// in an image editor, which are usually sRGB-encoded.
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct Color {
     pub r: f32,
@@ -65,6 +94,152 @@ impl Color {
     pub const fn with_a(self, a: f32) -> Self {
         Self::new_rgba(self.r, self.g, self.b, a)
     }
+
+    pub fn map_rgb(self, f: impl Fn(f32) -> f32) -> Self {
+        Self::new_rgba(f(self.r), f(self.g), f(self.b), self.a)
+    }
+
+    /// 8-bit components, taken as is.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new_rgba(
+            f32::from(r) / 255.,
+            f32::from(g) / 255.,
+            f32::from(b) / 255.,
+            f32::from(a) / 255.,
+        )
+    }
+
+    pub fn to_rgba8(self) -> [u8; 4] {
+        self.to_array()
+            .map(|component| (component.clamp(0., 1.) * 255.).round() as u8)
+    }
+
+    /// 8-bit sRGB-encoded components, as in most images and colour pickers.
+    pub fn from_srgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::from_rgba8(r, g, b, a).srgb_to_linear()
+    }
+
+    pub fn to_srgba8(self) -> [u8; 4] {
+        self.linear_to_srgb().to_rgba8()
+    }
+
+    /// An sRGB colour written as `0xRRGGBBAA`.
+    pub fn hex(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_srgba8(r, g, b, a)
+    }
+
+    pub fn to_hex(self) -> u32 {
+        u32::from_be_bytes(self.to_srgba8())
+    }
+
+    /// An sRGB colour from hue in degrees, saturation and lightness in `0..=1`.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let chroma = (1. - (2. * lightness - 1.).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, lightness - chroma / 2.)
+    }
+
+    /// An sRGB colour from hue in degrees, saturation and value in `0..=1`.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let chroma = value * saturation;
+        Self::from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    fn from_hue_chroma(hue: f32, chroma: f32, min: f32) -> Self {
+        let hue = hue.rem_euclid(360.) / 60.;
+        let x = chroma * (1. - (hue % 2. - 1.).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+
+        Self::new_rgb(r + min, g + min, b + min).srgb_to_linear()
+    }
+
+    /// Returns hue in degrees, saturation and lightness of the sRGB-encoded colour.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, min, max) = self.hue_min_max();
+        let lightness = (max + min) / 2.;
+        let saturation = if max == min {
+            0.
+        } else {
+            (max - min) / (1. - (2. * lightness - 1.).abs())
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Returns hue in degrees, saturation and value of the sRGB-encoded colour.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, min, max) = self.hue_min_max();
+        let saturation = if max == 0. { 0. } else { (max - min) / max };
+
+        (hue, saturation, max)
+    }
+
+    fn hue_min_max(self) -> (f32, f32, f32) {
+        let Self { r, g, b, .. } = self.linear_to_srgb();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        let hue = if chroma == 0. {
+            0.
+        } else if max == r {
+            (g - b) / chroma
+        } else if max == g {
+            (b - r) / chroma + 2.
+        } else {
+            (r - g) / chroma + 4.
+        };
+
+        ((hue * 60.).rem_euclid(360.), min, max)
+    }
+
+    /// Encodes linear components with the sRGB curve. Alpha is left as is.
+    pub fn linear_to_srgb(self) -> Self {
+        self.map_rgb(|c| {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1. / 2.4) - 0.055
+            }
+        })
+    }
+
+    /// Decodes sRGB-encoded components into linear ones. Alpha is left as is.
+    pub fn srgb_to_linear(self) -> Self {
+        self.map_rgb(|c| {
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    }
+
+    /// Interpolates every component, `t = 0` giving `self` and `t = 1` giving `other`.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self::from(self.to_vec4().lerp(other.to_vec4(), t))
+    }
+
+    /// Multiplies the colour components by alpha.
+    pub fn premultiplied(self) -> Self {
+        self.map_rgb(|c| c * self.a)
+    }
+
+    /// Reverts [`Self::premultiplied`]. Fully transparent colours become black.
+    pub fn unpremultiplied(self) -> Self {
+        if self.a == 0. {
+            Self::TRANSPARENT
+        } else {
+            self.map_rgb(|c| c / self.a)
+        }
+    }
 }
 
 impl From<Color> for Vec4 {
@@ -72,3 +247,125 @@ impl From<Color> for Vec4 {
         value.to_vec4()
     }
 }
+
+impl From<Vec4> for Color {
+    fn from(value: Vec4) -> Self {
+        Self::new_rgba(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Color> for wgpu::Color {
+    fn from(value: Color) -> Self {
+        Self {
+            r: value.r.into(),
+            g: value.g.into(),
+            b: value.b.into(),
+            a: value.a.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid hex colour {:?}, expected #RRGGBB or #RRGGBBAA",
+            self.0
+        )
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses an sRGB hex colour, `#RRGGBB` or `#RRGGBBAA`.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorError(s.to_owned());
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+
+        let rgba = u32::from_str_radix(digits, 16).map_err(|_| error())?;
+        match digits.len() {
+            6 => Ok(Self::hex(rgba << 8 | 0xff)),
+            8 => Ok(Self::hex(rgba)),
+            _ => Err(error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(
+            close(actual.0, expected.0)
+                && close(actual.1, expected.1)
+                && close(actual.2, expected.2),
+            "{actual:?} != {expected:?}",
+        );
+    }
+
+    #[test]
+    fn hex_round_trips() {
+        for rgba in [
+            0x0000_0000,
+            0xffff_ffff,
+            0x3366_99cc,
+            0x1234_5678,
+            0x8080_80ff,
+        ] {
+            assert_eq!(Color::hex(rgba).to_hex(), rgba);
+        }
+        assert_eq!("#336699".parse(), Ok(Color::hex(0x3366_99ff)));
+    }
+
+    #[test]
+    fn srgba8_round_trips() {
+        for value in 0..=255 {
+            let color = Color::from_srgba8(value, value, value, value);
+            assert_eq!(color.to_srgba8(), [value; 4]);
+        }
+    }
+
+    #[test]
+    fn srgba8_decodes_to_linear() {
+        let color = Color::from_srgba8(128, 0, 255, 128);
+        assert_close((color.r, color.g, color.b), (0.2158, 0., 1.));
+        assert_eq!(color.a, 128. / 255.);
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        assert_eq!(Color::from_hsl(0., 1., 0.5), Color::RED);
+        for hsl in [
+            (0., 0., 0.25),
+            (30., 0.5, 0.5),
+            (200., 0.8, 0.3),
+            (330., 1., 0.75),
+        ] {
+            assert_close(Color::from_hsl(hsl.0, hsl.1, hsl.2).to_hsl(), hsl);
+        }
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        assert_eq!(Color::from_hsv(120., 1., 1.), Color::GREEN);
+        for hsv in [
+            (0., 0., 0.25),
+            (30., 0.5, 0.5),
+            (200., 0.8, 0.3),
+            (330., 1., 0.75),
+        ] {
+            assert_close(Color::from_hsv(hsv.0, hsv.1, hsv.2).to_hsv(), hsv);
+        }
+    }
+}
@@ -1,10 +1,11 @@
+use crate::buffers::InstanceSlice;
 use crate::buffers::MutVecBuffer;
 use crate::buffers::VecBuffer;
 use crate::shaders::mesh::in_vertex;
 use crate::shaders::mesh::BindGroups;
 use crate::shaders::mesh::InVertex;
 use crate::shaders::mesh::Transform;
-use crate::window::RenderWindow;
+use crate::window::RenderTarget;
 use crate::Color;
 use glam::vec2;
 use glam::Vec2;
@@ -39,7 +40,7 @@ impl Mesh {
         }
     }
 
-    pub fn rect(rw: &RenderWindow, size: Vec2) -> Self {
+    pub fn rect(rw: &dyn RenderTarget, size: Vec2) -> Self {
         Self::new_indexed(
             rw.device(),
             &[
@@ -52,7 +53,7 @@ impl Mesh {
         )
     }
 
-    pub fn square(rw: &RenderWindow, size: f32, ratio: f32) -> Self {
+    pub fn square(rw: &dyn RenderTarget, size: f32, ratio: f32) -> Self {
         Self::rect(rw, vec2(size, size * ratio))
     }
 
@@ -72,15 +73,46 @@ impl Mesh {
         &self,
         render_pass: &mut wgpu::RenderPass<'e>,
         pipeline: &MeshPipeline,
-        bind_groups: BindGroups<'e>,
+        bind_groups: BindGroups,
         transforms: &MutVecBuffer<Transform>,
+    ) {
+        let buffer = transforms.buffer();
+        let count = transforms.len() as _;
+        self.draw_from(render_pass, pipeline, bind_groups, buffer.slice(..), count);
+    }
+
+    /// Draws an instance for each transform in `transforms`.
+    pub fn draw_instances<'e>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'e>,
+        pipeline: &MeshPipeline,
+        bind_groups: BindGroups,
+        transforms: &InstanceSlice<Transform>,
+    ) {
+        let count = transforms.count();
+        self.draw_from(
+            render_pass,
+            pipeline,
+            bind_groups,
+            transforms.buffer_slice(),
+            count,
+        );
+    }
+
+    fn draw_from<'e>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'e>,
+        pipeline: &MeshPipeline,
+        bind_groups: BindGroups,
+        transforms: wgpu::BufferSlice,
+        count: u32,
     ) {
         pipeline.set(render_pass);
         bind_groups.set(render_pass);
         render_pass.set_vertex_buffer(0, self.vertices().buffer().slice(..));
-        render_pass.set_vertex_buffer(1, transforms.buffer().slice(..));
+        render_pass.set_vertex_buffer(1, transforms);
 
-        let instances = 0..transforms.values().len() as _;
+        let instances = 0..count;
         if let Some(indices) = self.indices() {
             render_pass.set_index_buffer(indices.buffer().slice(..), INDEX_FORMAT);
             render_pass.draw_indexed(0..indices.count() as _, 0, instances);
@@ -1,16 +1,21 @@
+use crate::drawing::StencilMode;
 use crate::shaders;
-use crate::window::RenderWindow;
+use crate::window::RenderTarget;
 use wgpu::BlendComponent;
 use wgpu::BlendFactor;
 use wgpu::BlendState;
 use wgpu::ColorTargetState;
-use wgpu::ColorWrites;
 use wgpu::VertexStepMode;
 
 pub struct MeshPipeline(wgpu::RenderPipeline);
 
 impl MeshPipeline {
-    pub fn new(rw: &RenderWindow) -> MeshPipeline {
+    pub fn new(rw: &dyn RenderTarget) -> MeshPipeline {
+        Self::new_stencil(rw, StencilMode::Test)
+    }
+
+    /// Writes depth when drawing colour, if the target has a depth buffer.
+    pub fn new_stencil(rw: &dyn RenderTarget, stencil_mode: StencilMode) -> MeshPipeline {
         use shaders::mesh::*;
 
         let device = rw.device();
@@ -27,7 +32,7 @@ impl MeshPipeline {
                 },
                 alpha: BlendComponent::OVER,
             }),
-            write_mask: ColorWrites::ALL,
+            write_mask: stencil_mode.color_writes(),
         })];
 
         let vertex_entry = vs_main_entry(VertexStepMode::Vertex, VertexStepMode::Instance);
@@ -43,8 +48,11 @@ impl MeshPipeline {
                 vertex,
                 fragment,
                 primitive: Default::default(),
-                depth_stencil: None,
-                multisample: Default::default(),
+                depth_stencil: stencil_mode.depth_stencil_state(rw, true),
+                multisample: wgpu::MultisampleState {
+                    count: rw.sample_count(),
+                    ..Default::default()
+                },
                 multiview: None,
                 cache: None,
             }),
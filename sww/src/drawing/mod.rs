@@ -1,3 +1,9 @@
 mod mesh;
+mod sdf;
+mod shape;
+mod stencil;
 
 pub use mesh::*;
+pub use sdf::*;
+pub use shape::*;
+pub use stencil::*;
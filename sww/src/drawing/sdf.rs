@@ -0,0 +1,73 @@
+use crate::buffers::InstanceSlice;
+use crate::buffers::VecBuffer;
+use crate::drawing::StencilMode;
+use crate::shaders;
+use crate::shaders::sdf::QuadVertex;
+use crate::shaders::sdf::SdfInstance;
+use crate::window::RenderTarget;
+use glam::vec2;
+use wgpu::BlendState;
+use wgpu::ColorTargetState;
+use wgpu::ColorWrites;
+use wgpu::VertexStepMode;
+
+/// Draws anti-aliased rounded boxes, borders and shadows, described by
+/// [`SdfInstance`]s.
+pub struct SdfPipeline {
+    pipeline: wgpu::RenderPipeline,
+    quad: VecBuffer<QuadVertex>,
+}
+
+impl SdfPipeline {
+    pub fn new(rw: &dyn RenderTarget) -> Self {
+        use shaders::sdf::*;
+
+        let device = rw.device();
+        let layout = create_pipeline_layout(device);
+        let shader = create_shader_module(device);
+
+        let targets = [Some(ColorTargetState {
+            format: rw.swapchain_format(),
+            blend: Some(BlendState::ALPHA_BLENDING),
+            write_mask: ColorWrites::ALL,
+        })];
+
+        let vertex_entry = vs_main_entry(VertexStepMode::Vertex, VertexStepMode::Instance);
+        let vertex = vertex_state(&shader, &vertex_entry);
+
+        let fragment_entry = fs_main_entry(targets);
+        let fragment = Some(fragment_state(&shader, &fragment_entry));
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Sdf"),
+            layout: Some(&layout),
+            vertex,
+            fragment,
+            primitive: Default::default(),
+            depth_stencil: StencilMode::Test.depth_stencil_state(rw, false),
+            multisample: wgpu::MultisampleState {
+                count: rw.sample_count(),
+                ..Default::default()
+            },
+            multiview: None,
+            cache: None,
+        });
+
+        let quad = VecBuffer::new(
+            device,
+            &[(0., 0.), (0., 1.), (1., 1.), (0., 0.), (1., 1.), (1., 0.)]
+                .map(|(x, y)| quad_vertex(vec2(x, y))),
+            wgpu::BufferUsages::VERTEX,
+        );
+
+        Self { pipeline, quad }
+    }
+
+    /// Draws `instances`.
+    pub fn draw(&self, render_pass: &mut wgpu::RenderPass, instances: &InstanceSlice<SdfInstance>) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_vertex_buffer(0, self.quad.buffer().slice(..));
+        render_pass.set_vertex_buffer(1, instances.buffer_slice());
+        render_pass.draw(0..self.quad.count() as _, 0..instances.count());
+    }
+}
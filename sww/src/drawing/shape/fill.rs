@@ -0,0 +1,73 @@
+use super::Index;
+use glam::Vec2;
+
+/// Triangulates a simple polygon of either winding by ear clipping.
+pub(super) fn triangulate(points: &[Vec2]) -> Vec<Index> {
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    let winding = signed_area(points).signum();
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut indices = Vec::with_capacity((points.len() - 2) * 3);
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let corner = |i: usize| {
+            (
+                remaining[(i + len - 1) % len],
+                remaining[i],
+                remaining[(i + 1) % len],
+            )
+        };
+
+        let is_ear = |i: usize| {
+            let (a, b, c) = corner(i);
+            let (pa, pb, pc) = (points[a], points[b], points[c]);
+            cross(pb - pa, pc - pb) * winding > 0.
+                && (remaining.iter())
+                    .filter(|&&other| other != a && other != b && other != c)
+                    .all(|&other| !in_triangle(points[other], pa, pb, pc))
+        };
+
+        // collinear corners add no area, so they are dropped without a triangle
+        let is_flat = |i: usize| {
+            let (a, b, c) = corner(i);
+            cross(points[b] - points[a], points[c] - points[b]).abs() <= f32::EPSILON
+        };
+        if let Some(flat) = (0..len).find(|&i| is_flat(i)) {
+            remaining.remove(flat);
+            continue;
+        }
+
+        // a self-intersecting polygon may have no ears, then any corner is cut
+        let ear = (0..len).find(|&i| is_ear(i)).unwrap_or(0);
+        let (a, b, c) = corner(ear);
+        indices.extend([a, b, c].map(|index| index as Index));
+        remaining.remove(ear);
+    }
+
+    indices.extend(remaining.iter().map(|&index| index as Index));
+    indices
+}
+
+fn signed_area(points: &[Vec2]) -> f32 {
+    (points.iter().zip(points.iter().cycle().skip(1)))
+        .map(|(&a, &b)| cross(a, b))
+        .sum::<f32>()
+        / 2.
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.perp_dot(b)
+}
+
+fn in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    let d1 = cross(b - a, point - a);
+    let d2 = cross(c - b, point - b);
+    let d3 = cross(a - c, point - c);
+
+    let has_negative = d1 < 0. || d2 < 0. || d3 < 0.;
+    let has_positive = d1 > 0. || d2 > 0. || d3 > 0.;
+    !(has_negative && has_positive)
+}
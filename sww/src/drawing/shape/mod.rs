@@ -0,0 +1,250 @@
+use super::Index;
+use super::Mesh;
+use crate::shaders::mesh::in_vertex;
+use crate::shaders::mesh::InVertex;
+use crate::window::RenderTarget;
+use crate::Color;
+use glam::vec2;
+use glam::Vec2;
+use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::TAU;
+
+mod fill;
+mod stroke;
+
+pub use stroke::*;
+
+/// Maximum distance between a curve and the segments approximating it.
+pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+/// Vertices and indices of a tessellated shape.
+#[derive(Debug, Clone, Default)]
+pub struct Geometry {
+    pub vertices: Vec<InVertex>,
+    pub indices: Vec<Index>,
+}
+
+impl Geometry {
+    /// Texture coordinates span the bounding box of the geometry.
+    fn from_triangles(positions: &[Vec2], indices: Vec<Index>) -> Self {
+        let min = positions
+            .iter()
+            .copied()
+            .reduce(Vec2::min)
+            .unwrap_or_default();
+        let max = positions
+            .iter()
+            .copied()
+            .reduce(Vec2::max)
+            .unwrap_or_default();
+        let size = (max - min).max(Vec2::splat(f32::EPSILON));
+
+        Self {
+            vertices: (positions.iter())
+                .map(|&position| in_vertex(position, Color::WHITE.into(), (position - min) / size))
+                .collect(),
+            indices,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Adds the triangles of `other`, keeping the texture coordinates of both.
+    pub fn append(&mut self, other: &Self) {
+        let offset = self.vertices.len() as Index;
+        self.vertices.extend_from_slice(&other.vertices);
+        (self.indices).extend(other.indices.iter().map(|index| index + offset));
+    }
+
+    pub fn mesh(&self, rw: &dyn RenderTarget) -> Mesh {
+        Mesh::new_indexed(rw.device(), &self.vertices, &self.indices)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Segment {
+    Line(Vec2),
+    Arc {
+        center: Vec2,
+        radii: Vec2,
+        start_angle: f32,
+        end_angle: f32,
+    },
+}
+
+/// A single contour made of lines and elliptic arcs, which is flattened on tessellation.
+///
+/// Angles are in radians, starting at `+x` and going towards `+y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    segments: Vec<Segment>,
+    closed: bool,
+    tolerance: f32,
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+            closed: false,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum distance between arcs and their segments, in the units of the path.
+    pub fn with_tolerance(self, tolerance: f32) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    pub fn line_to(mut self, point: Vec2) -> Self {
+        self.segments.push(Segment::Line(point));
+        self
+    }
+
+    /// Continues with an elliptic arc, connected to the previous point by a line.
+    pub fn arc_to(mut self, center: Vec2, radii: Vec2, start_angle: f32, end_angle: f32) -> Self {
+        self.segments.push(Segment::Arc {
+            center,
+            radii,
+            start_angle,
+            end_angle,
+        });
+        self
+    }
+
+    /// Connects the last point back to the first one.
+    pub fn close(self) -> Self {
+        Self {
+            closed: true,
+            ..self
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the contour as points, with arcs split into segments and duplicates removed.
+    pub fn flatten(&self) -> Vec<Vec2> {
+        let mut points = Vec::<Vec2>::new();
+        let mut push = |point: Vec2| {
+            if (points.last()).is_none_or(|last| last.distance_squared(point) > 1e-12) {
+                points.push(point);
+            }
+        };
+
+        for &segment in &self.segments {
+            match segment {
+                Segment::Line(point) => push(point),
+
+                Segment::Arc {
+                    center,
+                    radii,
+                    start_angle,
+                    end_angle,
+                } => {
+                    let sweep = end_angle - start_angle;
+                    let count = arc_segment_count(radii.max_element(), sweep, self.tolerance);
+                    for i in 0..=count {
+                        let angle = start_angle + sweep * i as f32 / count as f32;
+                        push(center + radii * Vec2::from_angle(angle));
+                    }
+                }
+            }
+        }
+
+        if self.closed
+            && points.len() > 1
+            && points[0].distance_squared(points[points.len() - 1]) <= 1e-12
+        {
+            points.pop();
+        }
+        points
+    }
+
+    /// Triangulates the area inside the closed contour, which must not intersect itself.
+    pub fn fill(&self) -> Geometry {
+        let points = self.flatten();
+        Geometry::from_triangles(&points, fill::triangulate(&points))
+    }
+
+    pub fn stroke(&self, stroke: &Stroke) -> Geometry {
+        let points = self.flatten();
+        stroke::stroke(&points, self.closed, stroke, self.tolerance)
+    }
+}
+
+/// Number of segments keeping an arc within `tolerance` of its chords.
+fn arc_segment_count(radius: f32, sweep: f32, tolerance: f32) -> u32 {
+    let max_angle = if radius > tolerance {
+        2. * (1. - tolerance / radius).acos()
+    } else {
+        FRAC_PI_2
+    };
+
+    ((sweep.abs() / max_angle).ceil() as u32).clamp(1, 1024)
+}
+
+pub fn polyline(points: impl IntoIterator<Item = Vec2>) -> Path {
+    (points.into_iter()).fold(Path::new(), Path::line_to)
+}
+
+pub fn polygon(points: impl IntoIterator<Item = Vec2>) -> Path {
+    polyline(points).close()
+}
+
+pub fn rect(top_left: Vec2, size: Vec2) -> Path {
+    polygon([
+        top_left,
+        top_left + vec2(size.x, 0.),
+        top_left + size,
+        top_left + vec2(0., size.y),
+    ])
+}
+
+/// A rectangle with its corners rounded by `radius`, which is clamped to fit.
+pub fn rounded_rect(top_left: Vec2, size: Vec2, radius: f32) -> Path {
+    let radius = radius.min(size.x.abs() / 2.).min(size.y.abs() / 2.).max(0.);
+    if radius == 0. {
+        return rect(top_left, size);
+    }
+
+    let (min, max) = (top_left + radius, top_left + size - radius);
+    let radii = Vec2::splat(radius);
+
+    Path::new()
+        .arc_to(vec2(max.x, min.y), radii, -FRAC_PI_2, 0.)
+        .arc_to(max, radii, 0., FRAC_PI_2)
+        .arc_to(vec2(min.x, max.y), radii, FRAC_PI_2, 2. * FRAC_PI_2)
+        .arc_to(min, radii, 2. * FRAC_PI_2, 3. * FRAC_PI_2)
+        .close()
+}
+
+pub fn ellipse(center: Vec2, radii: Vec2) -> Path {
+    Path::new().arc_to(center, radii, 0., TAU).close()
+}
+
+pub fn circle(center: Vec2, radius: f32) -> Path {
+    ellipse(center, Vec2::splat(radius))
+}
+
+/// An open arc, use [`Path::close`] on it for a circular segment.
+pub fn arc(center: Vec2, radius: f32, start_angle: f32, end_angle: f32) -> Path {
+    Path::new().arc_to(center, Vec2::splat(radius), start_angle, end_angle)
+}
+
+/// A pie slice from `center`.
+pub fn sector(center: Vec2, radius: f32, start_angle: f32, end_angle: f32) -> Path {
+    Path::new()
+        .line_to(center)
+        .arc_to(center, Vec2::splat(radius), start_angle, end_angle)
+        .close()
+}
@@ -0,0 +1,173 @@
+use super::arc_segment_count;
+use super::Geometry;
+use super::Index;
+use glam::Vec2;
+
+/// How two stroked segments are connected.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineJoin {
+    /// Extends the edges until they meet, falling back to a bevel beyond the limit.
+    Miter {
+        limit: f32,
+    },
+    #[default]
+    Bevel,
+    Round,
+}
+
+/// How the ends of an open stroke look.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineCap {
+    /// Ends exactly at the end points.
+    #[default]
+    Butt,
+    /// Extends by half the width past the end points.
+    Square,
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+}
+
+impl Stroke {
+    pub const fn new(width: f32) -> Self {
+        Self {
+            width,
+            join: LineJoin::Bevel,
+            cap: LineCap::Butt,
+        }
+    }
+
+    pub const fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
+    pub const fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+}
+
+#[derive(Default)]
+struct Builder {
+    positions: Vec<Vec2>,
+    indices: Vec<Index>,
+}
+
+impl Builder {
+    fn vertex(&mut self, position: Vec2) -> Index {
+        self.positions.push(position);
+        self.positions.len() as Index - 1
+    }
+
+    fn triangle(&mut self, a: Vec2, b: Vec2, c: Vec2) {
+        let indices = [a, b, c].map(|position| self.vertex(position));
+        self.indices.extend(indices);
+    }
+
+    fn quad(&mut self, a: Vec2, b: Vec2, c: Vec2, d: Vec2) {
+        let [a, b, c, d] = [a, b, c, d].map(|position| self.vertex(position));
+        self.indices.extend([a, b, c, a, c, d]);
+    }
+
+    /// Fills the circular sector around `center` from `from` to `to`, taking the shorter way.
+    fn fan(&mut self, center: Vec2, from: Vec2, to: Vec2, tolerance: f32) {
+        let radius = from.length();
+        let sweep = from.angle_to(to);
+        let count = arc_segment_count(radius, sweep, tolerance);
+
+        let center_index = self.vertex(center);
+        let mut previous = self.vertex(center + from);
+        for i in 1..=count {
+            let offset = Vec2::from_angle(sweep * i as f32 / count as f32).rotate(from);
+            let next = self.vertex(center + offset);
+            self.indices.extend([center_index, previous, next]);
+            previous = next;
+        }
+    }
+}
+
+pub(super) fn stroke(points: &[Vec2], closed: bool, stroke: &Stroke, tolerance: f32) -> Geometry {
+    let half_width = stroke.width / 2.;
+    let mut builder = Builder::default();
+    if points.len() < 2 || half_width <= 0. {
+        return Geometry::default();
+    }
+
+    let segment_count = if closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+    let segment = |i: usize| (points[i], points[(i + 1) % points.len()]);
+    let normal = |(a, b): (Vec2, Vec2)| (b - a).normalize_or_zero().perp() * half_width;
+
+    for i in 0..segment_count {
+        let (mut a, mut b) = segment(i);
+        let direction = (b - a).normalize_or_zero();
+
+        if !closed && stroke.cap == LineCap::Square {
+            if i == 0 {
+                a -= direction * half_width;
+            }
+            if i == segment_count - 1 {
+                b += direction * half_width;
+            }
+        }
+
+        let normal = normal((a, b));
+        builder.quad(a + normal, b + normal, b - normal, a - normal);
+    }
+
+    let joins = if closed {
+        0..segment_count
+    } else {
+        1..segment_count
+    };
+    for i in joins {
+        let previous = normal(segment((i + segment_count - 1) % segment_count));
+        let next = normal(segment(i));
+        let point = points[i];
+
+        // the outer side of the turn is where the edges of the two segments leave a gap
+        let turn = previous.perp_dot(next);
+        if turn.abs() <= f32::EPSILON * half_width * half_width {
+            continue;
+        }
+        let side = if turn > 0. { -1. } else { 1. };
+        let (from, to) = (previous * side, next * side);
+
+        match stroke.join {
+            LineJoin::Bevel => builder.triangle(point, point + from, point + to),
+
+            LineJoin::Miter { limit } => {
+                let miter = (from + to).normalize_or_zero();
+                let length = half_width / miter.dot(from / half_width).max(f32::EPSILON);
+                if length <= limit * half_width {
+                    builder.quad(point, point + from, point + miter * length, point + to);
+                } else {
+                    builder.triangle(point, point + from, point + to);
+                }
+            }
+
+            LineJoin::Round => builder.fan(point, from, to, tolerance),
+        }
+    }
+
+    if !closed && stroke.cap == LineCap::Round {
+        let first = normal(segment(0));
+        let last = normal(segment(segment_count - 1));
+        let end = points[points.len() - 1];
+
+        // the normal turned by a quarter points backwards, each half circle is two quarters
+        builder.fan(points[0], first, first.perp(), tolerance);
+        builder.fan(points[0], first.perp(), -first, tolerance);
+        builder.fan(end, last, -last.perp(), tolerance);
+        builder.fan(end, -last.perp(), -last, tolerance);
+    }
+
+    Geometry::from_triangles(&builder.positions, builder.indices)
+}
@@ -0,0 +1,83 @@
+use crate::window::RenderTarget;
+
+/// How a pipeline uses the stencil buffer of its target.
+///
+/// Nested masks are drawn by incrementing the stencil where it equals the reference of the
+/// enclosing mask, then drawing with [`StencilMode::Test`] and the incremented reference.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StencilMode {
+    /// Draws where the stencil equals the reference, which is everywhere for reference 0.
+    #[default]
+    Test,
+    /// Increments the stencil where it equals the reference, without drawing colour.
+    Increment,
+    /// Decrements the stencil where it equals the reference, without drawing colour.
+    Decrement,
+}
+
+impl StencilMode {
+    pub fn color_writes(self) -> wgpu::ColorWrites {
+        match self {
+            Self::Test => wgpu::ColorWrites::ALL,
+            Self::Increment | Self::Decrement => wgpu::ColorWrites::empty(),
+        }
+    }
+
+    /// Matches the depth/stencil buffer of `rw`, if it has one. Depth is tested with
+    /// [`wgpu::CompareFunction::GreaterEqual`], so higher values are drawn over lower ones.
+    pub fn depth_stencil_state(
+        self,
+        rw: &dyn RenderTarget,
+        depth_write_enabled: bool,
+    ) -> Option<wgpu::DepthStencilState> {
+        let format = rw.depth_stencil_format()?;
+
+        let (depth_compare, depth_write_enabled, pass_op) = match self {
+            Self::Test => (
+                wgpu::CompareFunction::GreaterEqual,
+                depth_write_enabled,
+                wgpu::StencilOperation::Keep,
+            ),
+            Self::Increment => (
+                wgpu::CompareFunction::Always,
+                false,
+                wgpu::StencilOperation::IncrementClamp,
+            ),
+            Self::Decrement => (
+                wgpu::CompareFunction::Always,
+                false,
+                wgpu::StencilOperation::DecrementClamp,
+            ),
+        };
+
+        let stencil = if format.has_stencil_aspect() {
+            let face = wgpu::StencilFaceState {
+                compare: wgpu::CompareFunction::Equal,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op,
+            };
+
+            wgpu::StencilState {
+                front: face,
+                back: face,
+                read_mask: !0,
+                write_mask: !0,
+            }
+        } else {
+            Default::default()
+        };
+
+        Some(wgpu::DepthStencilState {
+            format,
+            depth_write_enabled: depth_write_enabled && format.has_depth_aspect(),
+            depth_compare: if format.has_depth_aspect() {
+                depth_compare
+            } else {
+                wgpu::CompareFunction::Always
+            },
+            stencil,
+            bias: Default::default(),
+        })
+    }
+}
@@ -1,3 +1,4 @@
+pub use image;
 pub use wgpu;
 
 pub mod app;
@@ -0,0 +1,281 @@
+use super::DefaultView;
+use crate::shaders::mesh::Rectangle;
+use crate::vec2;
+use crate::window::RenderTarget;
+use crate::Vec2;
+use image::EncodableLayout;
+use image::RgbaImage;
+
+const DEFAULT_PADDING: u32 = 1;
+
+/// Where an image was placed in an [`Atlas`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasHandle {
+    /// Index of the page in [`Atlas::pages`].
+    pub page: usize,
+    /// Normalized rectangle of the image within its page, usable as `texture_rect`.
+    pub texture_rect: Rectangle,
+    /// Size of the image in pixels.
+    pub size: Vec2,
+}
+
+/// Packs many images into a few large textures, so that they can be drawn with one bind group.
+///
+/// Pages don't grow, so that handles stay valid. Instead, a new page is added whenever an image
+/// does not fit into the existing ones. Images larger than a page get a page of their own, up to
+/// the maximum texture size.
+pub struct Atlas {
+    page_size: u32,
+    padding: u32,
+    label: Option<&'static str>,
+    pages: Vec<AtlasPage>,
+}
+
+pub struct AtlasPage {
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    packer: ShelfPacker,
+}
+
+struct ShelfPacker {
+    size: u32,
+    padding: u32,
+    shelves: Vec<Shelf>,
+    next_y: u32,
+}
+
+struct Shelf {
+    y: u32,
+    height: u32,
+    next_x: u32,
+}
+
+impl Atlas {
+    pub fn new(page_size: u32) -> Self {
+        Self {
+            page_size,
+            padding: DEFAULT_PADDING,
+            label: None,
+            pages: Vec::new(),
+        }
+    }
+
+    /// Empty pixels left between images, so that filtering does not mix neighbours.
+    pub fn with_padding(self, padding: u32) -> Self {
+        Self { padding, ..self }
+    }
+
+    pub fn with_label(self, label: &'static str) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    pub fn pages(&self) -> &[AtlasPage] {
+        &self.pages
+    }
+
+    pub fn page(&self, handle: AtlasHandle) -> &AtlasPage {
+        &self.pages[handle.page]
+    }
+
+    /// Returns `None` if `image` is larger than the maximum texture size.
+    pub fn add(&mut self, rw: &dyn RenderTarget, image: &RgbaImage) -> Option<AtlasHandle> {
+        let (width, height) = image.dimensions();
+        let (page, x, y) = self.allocate(rw, width, height)?;
+        let page_size = self.pages[page].packer.size as f32;
+        self.pages[page].write(rw, x, y, image);
+
+        let size = vec2(width as _, height as _);
+        Some(AtlasHandle {
+            page,
+            texture_rect: Rectangle::new(vec2(x as _, y as _) / page_size, size / page_size),
+            size,
+        })
+    }
+
+    fn allocate(
+        &mut self,
+        rw: &dyn RenderTarget,
+        width: u32,
+        height: u32,
+    ) -> Option<(usize, u32, u32)> {
+        for (index, page) in self.pages.iter_mut().enumerate().rev() {
+            if let Some((x, y)) = page.packer.allocate(width, height) {
+                return Some((index, x, y));
+            }
+        }
+
+        let max_size = rw.device().limits().max_texture_dimension_2d;
+        let size = new_page_size(self.page_size, max_size, width, height)?;
+        let mut page = AtlasPage::new(rw, size, self.padding, self.label);
+        let (x, y) = page.packer.allocate(width, height).unwrap();
+        self.pages.push(page);
+        Some((self.pages.len() - 1, x, y))
+    }
+}
+
+/// Size of a new page for a `width` by `height` image, or `None` if it's larger than `max_size`.
+fn new_page_size(page_size: u32, max_size: u32, width: u32, height: u32) -> Option<u32> {
+    (width <= max_size && height <= max_size)
+        .then(|| page_size.max(width).max(height).min(max_size))
+}
+
+impl AtlasPage {
+    fn new(rw: &dyn RenderTarget, size: u32, padding: u32, label: Option<&'static str>) -> Self {
+        let texture = rw.device().create_texture(&wgpu::TextureDescriptor {
+            label,
+            size: wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        Self {
+            texture_view: texture.default_view(),
+            texture,
+            packer: ShelfPacker::new(size, padding),
+        }
+    }
+
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    fn write(&self, rw: &dyn RenderTarget, x: u32, y: u32, image: &RgbaImage) {
+        let (width, height) = image.dimensions();
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        rw.queue().write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x, y, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            image.as_bytes(),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: Some(height),
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+}
+
+impl ShelfPacker {
+    fn new(size: u32, padding: u32) -> Self {
+        Self {
+            size,
+            padding,
+            shelves: Vec::new(),
+            next_y: 0,
+        }
+    }
+
+    /// Places the rectangle on the tightest shelf it fits, or opens a new shelf.
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        if width > self.size || height > self.size {
+            return None;
+        }
+
+        // nothing is placed past the edges, so padding isn't needed there
+        let width = (width + self.padding).min(self.size);
+        let height = (height + self.padding).min(self.size);
+
+        let size = self.size;
+        if let Some(shelf) = (self.shelves.iter_mut())
+            .filter(|shelf| height <= shelf.height && shelf.next_x + width <= size)
+            .min_by_key(|shelf| shelf.height)
+        {
+            let x = shelf.next_x;
+            shelf.next_x += width;
+            return Some((x, shelf.y));
+        }
+
+        if self.next_y + height > self.size {
+            return None;
+        }
+
+        let y = self.next_y;
+        self.next_y += height;
+        self.shelves.push(Shelf {
+            y,
+            height,
+            next_x: width,
+        });
+        Some((0, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shelf_fills_left_to_right() {
+        let mut packer = ShelfPacker::new(100, 0);
+        assert_eq!(packer.allocate(40, 10), Some((0, 0)));
+        assert_eq!(packer.allocate(40, 10), Some((40, 0)));
+    }
+
+    #[test]
+    fn full_shelf_wraps_to_a_new_one() {
+        let mut packer = ShelfPacker::new(100, 0);
+        assert_eq!(packer.allocate(60, 10), Some((0, 0)));
+        assert_eq!(packer.allocate(60, 20), Some((0, 10)));
+        // the tightest shelf with room
+        assert_eq!(packer.allocate(30, 10), Some((60, 0)));
+    }
+
+    #[test]
+    fn full_page_needs_a_new_one() {
+        let mut packer = ShelfPacker::new(100, 0);
+        assert_eq!(packer.allocate(100, 60), Some((0, 0)));
+        assert_eq!(packer.allocate(10, 60), None);
+        assert_eq!(packer.allocate(100, 40), Some((0, 60)));
+        assert_eq!(packer.allocate(1, 1), None);
+    }
+
+    #[test]
+    fn padding_separates_rectangles() {
+        let mut packer = ShelfPacker::new(100, 2);
+        assert_eq!(packer.allocate(10, 10), Some((0, 0)));
+        assert_eq!(packer.allocate(10, 10), Some((12, 0)));
+        assert_eq!(packer.allocate(90, 10), Some((0, 12)));
+    }
+
+    #[test]
+    fn padding_is_dropped_at_the_edges() {
+        let mut packer = ShelfPacker::new(100, 2);
+        assert_eq!(packer.allocate(100, 100), Some((0, 0)));
+    }
+
+    #[test]
+    fn oversized_images_get_their_own_page() {
+        assert_eq!(new_page_size(256, 4096, 10, 10), Some(256));
+        assert_eq!(new_page_size(256, 4096, 300, 10), Some(300));
+        assert_eq!(new_page_size(8192, 4096, 10, 10), Some(4096));
+        assert_eq!(new_page_size(256, 4096, 5000, 10), None);
+        assert_eq!(ShelfPacker::new(256, 0).allocate(300, 10), None);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::ColorSpace;
+use image::imageops;
+use image::imageops::FilterType;
 use image::EncodableLayout;
 use image::ImageReader;
 use image::RgbaImage;
@@ -5,12 +8,18 @@ use std::io::BufRead;
 use std::io::Seek;
 use wgpu::util::DeviceExt;
 
+mod atlas;
 mod error;
 mod extensions;
+mod readback;
+mod sampling;
 mod sheet;
 
+pub use atlas::*;
 pub use error::*;
 pub use extensions::*;
+pub use readback::*;
+pub use sampling::*;
 pub use sheet::*;
 
 pub fn read_image(reader: impl BufRead + Seek) -> Result<RgbaImage> {
@@ -28,10 +37,45 @@ pub fn read_texture(
     Ok(make_texture(device, queue, &read_image(reader)?))
 }
 
+/// Makes an sRGB texture without mipmaps, so that its pixels are decoded to linear when sampled.
+///
+/// Sprite sheets and atlases should stay without them, since their cells would bleed into each
+/// other in the smaller levels.
 pub fn make_texture(
     device: &wgpu::Device,
     queue: &wgpu::Queue,
     image: &image::RgbaImage,
+) -> wgpu::Texture {
+    make_texture_in(device, queue, image, ColorSpace::Srgb)
+}
+
+/// Like [`make_texture`], but with a full mipmap chain, so that it stays smooth when drawn
+/// downscaled.
+pub fn make_mipmapped_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    image: &image::RgbaImage,
+) -> wgpu::Texture {
+    create_texture(device, queue, image, ColorSpace::Srgb, &make_mipmaps(image))
+}
+
+/// Like [`make_texture`], but in the given colour space, such as [`ColorSpace::Linear`] for data
+/// textures.
+pub fn make_texture_in(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    image: &image::RgbaImage,
+    color_space: ColorSpace,
+) -> wgpu::Texture {
+    create_texture(device, queue, image, color_space, &[])
+}
+
+fn create_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    image: &RgbaImage,
+    color_space: ColorSpace,
+    mipmaps: &[RgbaImage],
 ) -> wgpu::Texture {
     device.create_texture_with_data(
         queue,
@@ -42,18 +86,46 @@ pub fn make_texture(
                 height: image.height(),
                 depth_or_array_layers: 1,
             },
-            mip_level_count: 1,
+            mip_level_count: mipmaps.len() as u32 + 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Rgba8Unorm,
+            format: color_space.apply(wgpu::TextureFormat::Rgba8Unorm),
             usage: wgpu::TextureUsages::TEXTURE_BINDING,
             view_formats: &[],
         },
         wgpu::util::TextureDataOrder::MipMajor,
-        image.as_bytes(),
+        &[image.as_bytes()]
+            .into_iter()
+            .chain(mipmaps.iter().map(|mipmap| mipmap.as_bytes()))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>(),
     )
 }
 
+/// Returns every mipmap level below the image itself, each half the size of the previous one.
+fn make_mipmaps(image: &RgbaImage) -> Vec<RgbaImage> {
+    let mut mipmaps = Vec::<RgbaImage>::new();
+
+    loop {
+        let previous = mipmaps.last().unwrap_or(image);
+        if previous.width() <= 1 && previous.height() <= 1 {
+            return mipmaps;
+        }
+
+        let (width, height) = (
+            (previous.width() / 2).max(1),
+            (previous.height() / 2).max(1),
+        );
+        mipmaps.push(imageops::resize(
+            previous,
+            width,
+            height,
+            FilterType::Triangle,
+        ));
+    }
+}
+
 pub fn make_default_texture(device: &wgpu::Device, queue: &wgpu::Queue) -> wgpu::Texture {
     make_texture(
         device,
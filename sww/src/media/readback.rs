@@ -0,0 +1,92 @@
+use image::RgbaImage;
+use std::sync::mpsc::channel;
+
+const BYTES_PER_PIXEL: u32 = 4;
+
+/// A buffer that a texture can be copied into to read its pixels back on the CPU.
+///
+/// Rows of the copy have to be aligned to [`wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`], so the
+/// padding is stripped while converting into an [`RgbaImage`].
+pub struct TextureReadback {
+    buffer: wgpu::Buffer,
+    size: wgpu::Extent3d,
+    padded_bytes_per_row: u32,
+    is_bgra: bool,
+}
+
+impl TextureReadback {
+    pub fn new(device: &wgpu::Device, texture: &wgpu::Texture) -> Self {
+        let format = texture.format();
+        let is_bgra = match format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            _ => panic!("cannot read back a texture of format {format:?}"),
+        };
+
+        let size = texture.size();
+        let padded_bytes_per_row =
+            (size.width * BYTES_PER_PIXEL).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("TextureReadback"),
+            size: wgpu::BufferAddress::from(padded_bytes_per_row * size.height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            buffer,
+            size,
+            padded_bytes_per_row,
+            is_bgra,
+        }
+    }
+
+    pub fn copy(&self, encoder: &mut wgpu::CommandEncoder, texture: &wgpu::Texture) {
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &self.buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: Some(self.size.height),
+                },
+            },
+            self.size,
+        );
+    }
+
+    /// Waits for the copy to finish. The commands containing it must already be submitted.
+    pub fn read(self, device: &wgpu::Device) -> RgbaImage {
+        let slice = self.buffer.slice(..);
+
+        let (sender, receiver) = channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            sender.send(result).unwrap();
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .unwrap()
+            .expect("failed to map readback buffer");
+
+        let bytes_per_row = (self.size.width * BYTES_PER_PIXEL) as usize;
+        let mut pixels = Vec::with_capacity(bytes_per_row * self.size.height as usize);
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(self.padded_bytes_per_row as usize)
+        {
+            pixels.extend_from_slice(&row[..bytes_per_row]);
+        }
+        self.buffer.unmap();
+
+        if self.is_bgra {
+            for pixel in pixels.chunks_exact_mut(BYTES_PER_PIXEL as usize) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        RgbaImage::from_raw(self.size.width, self.size.height, pixels).unwrap()
+    }
+}
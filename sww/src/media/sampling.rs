@@ -0,0 +1,58 @@
+/// How texels are combined when a texture is scaled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Filter {
+    /// Smooth, blends neighbouring texels and mipmap levels.
+    #[default]
+    Linear,
+    /// Blocky, takes the closest texel. Meant for pixel art.
+    Nearest,
+}
+
+/// What is sampled outside of the `0..1` texture coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    /// Repeats the edge texels.
+    #[default]
+    Clamp,
+    Repeat,
+    /// Repeats the texture, flipping every other copy.
+    Mirror,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sampling {
+    pub filter: Filter,
+    pub wrap: Wrap,
+}
+
+impl Sampling {
+    pub const LINEAR: Self = Self::new(Filter::Linear, Wrap::Clamp);
+    pub const NEAREST: Self = Self::new(Filter::Nearest, Wrap::Clamp);
+
+    pub const fn new(filter: Filter, wrap: Wrap) -> Self {
+        Self { filter, wrap }
+    }
+
+    pub fn make_sampler(self, device: &wgpu::Device) -> wgpu::Sampler {
+        let filter_mode = match self.filter {
+            Filter::Linear => wgpu::FilterMode::Linear,
+            Filter::Nearest => wgpu::FilterMode::Nearest,
+        };
+        let address_mode = match self.wrap {
+            Wrap::Clamp => wgpu::AddressMode::ClampToEdge,
+            Wrap::Repeat => wgpu::AddressMode::Repeat,
+            Wrap::Mirror => wgpu::AddressMode::MirrorRepeat,
+        };
+
+        device.create_sampler(&wgpu::SamplerDescriptor {
+            label: None,
+            address_mode_u: address_mode,
+            address_mode_v: address_mode,
+            address_mode_w: address_mode,
+            mag_filter: filter_mode,
+            min_filter: filter_mode,
+            mipmap_filter: filter_mode,
+            ..Default::default()
+        })
+    }
+}
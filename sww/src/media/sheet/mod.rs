@@ -3,7 +3,7 @@ use super::DefaultView;
 use super::RgbaImage;
 use crate::shaders;
 use crate::vec2;
-use crate::window::RenderWindow;
+use crate::window::RenderTarget;
 use crate::Vec2;
 use std::marker::PhantomData;
 
@@ -18,7 +18,7 @@ pub struct Sheet<T> {
 }
 
 impl<T> Sheet<T> {
-    pub fn new(rw: &RenderWindow, image: RgbaImage) -> Self {
+    pub fn new(rw: &dyn RenderTarget, image: RgbaImage) -> Self {
         Self {
             size: vec2(image.width() as _, image.height() as _),
             texture_view: make_texture(rw.device(), rw.queue(), &image).default_view(),
@@ -1,5 +1,4 @@
 #![allow(clippy::derivable_impls)]
-use super::PADDING;
 use crate::Color;
 use glam::Mat2;
 use glam::Vec2;
@@ -54,7 +53,8 @@ impl Transform {
         Transform {
             matrix,
             translation,
-            _1: PADDING,
+            z: 0.,
+            _1: 0.,
             color,
             texture_rect,
         }
@@ -69,7 +69,8 @@ impl Transform {
         Transform {
             matrix: Mat2::from_diagonal(scale),
             translation,
-            _1: PADDING,
+            z: 0.,
+            _1: 0.,
             color: color.to_vec4(),
             texture_rect,
         }
@@ -78,6 +79,12 @@ impl Transform {
     pub const fn new(translation: Vec2, color: Color, texture_rect: Rectangle) -> Transform {
         Self::new_scale(translation, Vec2::ONE, color, texture_rect)
     }
+
+    /// Depth in `0..=1`, added to the depth of the global transform. With a depth buffer,
+    /// higher values are drawn over lower ones regardless of draw order.
+    pub const fn with_z(self, z: f32) -> Self {
+        Self { z, ..self }
+    }
 }
 
 impl Default for Transform {
@@ -107,6 +114,13 @@ impl Rectangle {
         }
     }
 
+    pub fn flip_vertically(self) -> Self {
+        Self {
+            top_left: self.top_left + Vec2::new(0., self.size.y),
+            size: self.size * Vec2::new(1., -1.),
+        }
+    }
+
     pub fn subrect(self, other: Rectangle) -> Rectangle {
         let top_left = self.top_left + other.top_left * self.size;
         let size = self.size * other.size;
@@ -20,7 +20,8 @@ fn transforms_then(a: Transform, b: Transform) -> Transform {
     return Transform(
         b.matrix * a.matrix,
         b.matrix * a.translation + b.translation,
-        PADDING,
+        a.z + b.z,
+        0.,
         a.color * b.color,
         rectangle_then(a.texture_rect, b.texture_rect),
     );
@@ -46,16 +47,18 @@ struct InVertex {
 struct Transform {
     matrix: mat2x2f,
     translation: vec2f,
-    _1: Padding,
+    z: f32,
+    _1: f32,
     color: vec4f,
     texture_rect: Rectangle,
 }
 struct InTransform {
     @location(3) matrix: vec4f,
     @location(4) translation: vec2f,
-    @location(5) _1: Padding,
-    @location(6) color: vec4f,
-    @location(7) texture_rect: vec4f,
+    @location(5) z: f32,
+    @location(6) _1: f32,
+    @location(7) color: vec4f,
+    @location(8) texture_rect: vec4f,
 }
 
 fn vec_to_mat(v: vec4f) -> mat2x2f {
@@ -66,7 +69,8 @@ fn in_to_transform(transform: InTransform) -> Transform {
     return Transform(
         vec_to_mat(transform.matrix),
         transform.translation,
-        PADDING,
+        transform.z,
+        0.,
         transform.color,
         vec_to_rect(transform.texture_rect),
     );
@@ -103,7 +107,7 @@ fn vs_main(
     let color = apply_transform_color(transform, in_vertex.color);
 
     return OutVertex(
-        vec4f(position, 0., 1.),
+        vec4f(position, transform.z, 1.),
         color,
         in_vertex.texture_coord,
         transform.texture_rect.top_left,
@@ -113,16 +117,14 @@ fn vs_main(
 }
 
 @group(1) @binding(0) var texture: texture_2d<f32>;
+@group(1) @binding(1) var texture_sampler: sampler;
 
 @fragment
 fn fs_main(in: InFragment) -> @location(0) vec4f {
-    var texture_coord_f = in.texture_coord;
-    texture_coord_f.y = 1. - texture_coord_f.y;
-    texture_coord_f = in.texture_rect_top_left + texture_coord_f * in.texture_rect_size;
+    var texture_coord = in.texture_coord;
+    texture_coord.y = 1. - texture_coord.y;
+    texture_coord = in.texture_rect_top_left + texture_coord * in.texture_rect_size;
 
-    let size = textureDimensions(texture);
-    var texture_coord = vec2u(texture_coord_f * vec2f(size));
-
-    let texel_color = textureLoad(texture, texture_coord, 0);
+    let texel_color = textureSample(texture, texture_sampler, texture_coord);
     return in.color * texel_color;
 }
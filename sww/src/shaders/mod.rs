@@ -1,3 +1,4 @@
 pub mod mesh;
+pub mod sdf;
 
 pub const PADDING: glam::Vec2 = glam::Vec2::new(0., 0.);
@@ -0,0 +1,52 @@
+use super::mesh::Rectangle;
+use crate::Color;
+use glam::vec4;
+use glam::Vec2;
+use glam::Vec4;
+
+include!(concat!(env!("OUT_DIR"), "/sdf.rs"));
+
+impl SdfInstance {
+    /// `rect` is in clip space, while `size` is the same rect in pixels.
+    pub fn new(rect: Rectangle, size: Vec2, fill_color: Color) -> Self {
+        Self {
+            rect: vec4(rect.top_left.x, rect.top_left.y, rect.size.x, rect.size.y),
+            corner_radii: Vec4::ZERO,
+            fill_color: fill_color.to_vec4(),
+            border_color: Color::TRANSPARENT.to_vec4(),
+            size,
+            border_width: 0.,
+            blur: 0.,
+        }
+    }
+
+    /// Radii of the top left, top right, bottom right and bottom left corners in pixels.
+    pub fn with_corner_radii(self, corner_radii: [f32; 4]) -> Self {
+        Self {
+            corner_radii: Vec4::from_array(corner_radii),
+            ..self
+        }
+    }
+
+    pub fn with_radius(self, radius: f32) -> Self {
+        self.with_corner_radii([radius; 4])
+    }
+
+    /// A border of `width` pixels drawn inside of the rect.
+    pub fn with_border(self, width: f32, color: Color) -> Self {
+        Self {
+            border_width: width,
+            border_color: color.to_vec4(),
+            ..self
+        }
+    }
+
+    /// Fades the edge out over `blur` pixels on both sides, as for a drop shadow.
+    pub fn with_blur(self, blur: f32) -> Self {
+        Self { blur, ..self }
+    }
+}
+
+pub fn quad_vertex(position: Vec2) -> QuadVertex {
+    QuadVertex { position }
+}
@@ -0,0 +1,94 @@
+struct QuadVertex {
+    @location(0) position: vec2f,
+}
+
+// should always be changed together with `SdfInstance::new`
+struct SdfInstance {
+    // top left and size in clip space
+    @location(1) rect: vec4f,
+    // top left, top right, bottom right, bottom left; in pixels
+    @location(2) corner_radii: vec4f,
+    @location(3) fill_color: vec4f,
+    @location(4) border_color: vec4f,
+    // size of the rect in pixels
+    @location(5) size: vec2f,
+    @location(6) border_width: f32,
+    // zero for a sharp anti-aliased edge, otherwise the distance over which the edge fades out
+    @location(7) blur: f32,
+}
+
+struct OutVertex {
+    @builtin(position) position: vec4f,
+    @location(0) local_position: vec2f,
+    @location(1) half_size: vec2f,
+    @location(2) corner_radii: vec4f,
+    @location(3) fill_color: vec4f,
+    @location(4) border_color: vec4f,
+    @location(5) border_width: f32,
+    @location(6) blur: f32,
+}
+
+struct InFragment {
+    @location(0) local_position: vec2f,
+    @location(1) half_size: vec2f,
+    @location(2) corner_radii: vec4f,
+    @location(3) fill_color: vec4f,
+    @location(4) border_color: vec4f,
+    @location(5) border_width: f32,
+    @location(6) blur: f32,
+}
+
+////////////////////////////////////////////////////////////
+
+@vertex
+fn vs_main(
+    in_vertex: QuadVertex,
+    instance: SdfInstance,
+) -> OutVertex {
+    // blurred edges reach outside of the rect
+    let spread = instance.blur;
+    let pixel_position = in_vertex.position * (instance.size + 2. * spread) - spread;
+    let pixel_scale = instance.rect.zw / instance.size;
+    let position = instance.rect.xy + pixel_position * pixel_scale;
+
+    return OutVertex(
+        vec4f(position, 0., 1.),
+        pixel_position - instance.size / 2.,
+        instance.size / 2.,
+        instance.corner_radii,
+        instance.fill_color,
+        instance.border_color,
+        instance.border_width,
+        instance.blur,
+    );
+}
+
+// `point` is relative to the center, with `y` pointing down
+fn rounded_box_distance(point: vec2f, half_size: vec2f, corner_radii: vec4f) -> f32 {
+    let side_radii = select(corner_radii.xw, corner_radii.yz, point.x > 0.);
+    let radius = select(side_radii.x, side_radii.y, point.y > 0.);
+
+    let q = abs(point) - half_size + radius;
+    return min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.))) - radius;
+}
+
+@fragment
+fn fs_main(in: InFragment) -> @location(0) vec4f {
+    let distance = rounded_box_distance(in.local_position, in.half_size, in.corner_radii);
+    let pixel = max(fwidth(distance), 1e-4);
+
+    if in.blur > 0. {
+        let alpha = 1. - smoothstep(-in.blur, in.blur, distance);
+        return vec4f(in.fill_color.rgb, in.fill_color.a * alpha);
+    }
+
+    let coverage = clamp(0.5 - distance / pixel, 0., 1.);
+    let inside_border = select(
+        1.,
+        clamp(0.5 - (distance + in.border_width) / pixel, 0., 1.),
+        in.border_width > 0.,
+    );
+
+    let color = mix(in.border_color, in.fill_color, inside_border);
+    return vec4f(color.rgb, color.a * coverage);
+}
@@ -18,6 +18,7 @@ pub mod event {
     pub type WindowEvent = winit::event::WindowEvent;
     pub type DeviceEvent = winit::event::DeviceEvent;
     pub type ActiveEventLoop = winit::event_loop::ActiveEventLoop;
+    pub type ControlFlow = winit::event_loop::ControlFlow;
     pub type AsyncRequestSerial = winit::event_loop::AsyncRequestSerial;
     pub type ActivationToken = winit::window::ActivationToken;
     pub type Theme = winit::window::Theme;
@@ -31,6 +32,11 @@ pub mod event {
     pub type FloatPosition = PhysicalPosition<f64>;
     pub type Delta = PhysicalPosition<f32>;
     pub type KeyboardModifiers = winit::event::Modifiers;
+    pub type ModifiersState = winit::keyboard::ModifiersState;
+    pub type Key = winit::keyboard::Key;
+    pub type NamedKey = winit::keyboard::NamedKey;
+    pub type KeyCode = winit::keyboard::KeyCode;
+    pub type PhysicalKey = winit::keyboard::PhysicalKey;
 }
 
 pub type Window = winit::window::Window;
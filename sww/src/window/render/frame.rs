@@ -1,4 +1,6 @@
-use crate::window::RenderWindow;
+use crate::window::RenderTarget;
+use image::RgbaImage;
+use std::sync::Arc;
 
 mod commands;
 mod surface;
@@ -13,17 +15,36 @@ pub struct Frame<'w> {
 
 impl<'w> Frame<'w> {
     pub fn new(
-        info: &'w RenderWindow,
+        info: &'w dyn RenderTarget,
         command_encoder: wgpu::CommandEncoder,
         surface_texture: wgpu::SurfaceTexture,
+        attachments: FrameAttachments,
     ) -> Self {
         Self {
             commands: FrameCommands::new(info, command_encoder),
-            surface: FrameSurface::new(surface_texture),
+            surface: FrameSurface::new(surface_texture, attachments),
+        }
+    }
+
+    pub fn new_offscreen(
+        info: &'w dyn RenderTarget,
+        command_encoder: wgpu::CommandEncoder,
+        texture: Arc<wgpu::Texture>,
+        attachments: FrameAttachments,
+    ) -> Self {
+        Self {
+            commands: FrameCommands::new(info, command_encoder),
+            surface: FrameSurface::new_offscreen(texture, attachments),
         }
     }
 
     pub fn commands_surface(&mut self) -> (&mut FrameCommands<'w>, &mut FrameSurface) {
         (&mut self.commands, &mut self.surface)
     }
+
+    /// Reads back everything drawn so far. The surface texture must have been created with
+    /// [`wgpu::TextureUsages::COPY_SRC`].
+    pub fn read_image(&mut self) -> RgbaImage {
+        self.commands.read_texture(self.surface.texture())
+    }
 }
@@ -1,12 +1,15 @@
-use crate::window::RenderWindow;
+use crate::media::TextureReadback;
+use crate::window::RenderTarget;
+use image::RgbaImage;
+use std::mem;
 
 pub struct FrameCommands<'w> {
-    info: &'w RenderWindow,
+    info: &'w dyn RenderTarget,
     encoder: Option<wgpu::CommandEncoder>,
 }
 
 impl<'w> FrameCommands<'w> {
-    pub(super) fn new(info: &'w RenderWindow, command_encoder: wgpu::CommandEncoder) -> Self {
+    pub(super) fn new(info: &'w dyn RenderTarget, command_encoder: wgpu::CommandEncoder) -> Self {
         Self {
             info,
             encoder: Some(command_encoder),
@@ -16,11 +19,28 @@ impl<'w> FrameCommands<'w> {
     pub fn encoder(&mut self) -> &mut wgpu::CommandEncoder {
         self.encoder.as_mut().unwrap()
     }
+
+    /// Submits the commands recorded so far and continues with a new encoder.
+    pub fn submit(&mut self) {
+        let new_encoder = self
+            .info
+            .device()
+            .create_command_encoder(&Default::default());
+        let command_encoder = mem::replace(self.encoder(), new_encoder);
+        self.info.queue().submit(Some(command_encoder.finish()));
+    }
+
+    pub fn read_texture(&mut self, texture: &wgpu::Texture) -> RgbaImage {
+        let readback = TextureReadback::new(self.info.device(), texture);
+        readback.copy(self.encoder(), texture);
+        self.submit();
+        readback.read(self.info.device())
+    }
 }
 
 impl Drop for FrameCommands<'_> {
     fn drop(&mut self) {
         let command_encoder = self.encoder.take().unwrap();
-        self.info.queue.submit(Some(command_encoder.finish()));
+        self.info.queue().submit(Some(command_encoder.finish()));
     }
 }
@@ -1,29 +1,132 @@
+use std::sync::Arc;
+
+enum FrameTexture {
+    Surface(wgpu::SurfaceTexture),
+    Offscreen(Arc<wgpu::Texture>),
+}
+
+/// Textures owned by the render target that frames draw into besides the surface.
+#[derive(Clone, Default)]
+pub struct FrameAttachments {
+    /// Drawn into and resolved into the surface, if multisampling is used.
+    pub multisampled: Option<Arc<wgpu::Texture>>,
+    pub depth_stencil: Option<Arc<wgpu::Texture>>,
+}
+
 pub struct FrameSurface {
-    texture: Option<wgpu::SurfaceTexture>,
+    texture: Option<FrameTexture>,
     view: wgpu::TextureView,
+    multisampled_view: Option<wgpu::TextureView>,
+    depth_stencil: Option<(wgpu::TextureView, wgpu::TextureFormat)>,
 }
 
 impl FrameSurface {
-    pub(super) fn new(surface_texture: wgpu::SurfaceTexture) -> Self {
+    pub(super) fn new(
+        surface_texture: wgpu::SurfaceTexture,
+        attachments: FrameAttachments,
+    ) -> Self {
         let view = surface_texture.texture.create_view(&Default::default());
+        Self::with_attachments(FrameTexture::Surface(surface_texture), view, attachments)
+    }
+
+    pub(super) fn new_offscreen(
+        texture: Arc<wgpu::Texture>,
+        attachments: FrameAttachments,
+    ) -> Self {
+        let view = texture.create_view(&Default::default());
+        Self::with_attachments(FrameTexture::Offscreen(texture), view, attachments)
+    }
+
+    fn with_attachments(
+        texture: FrameTexture,
+        view: wgpu::TextureView,
+        attachments: FrameAttachments,
+    ) -> Self {
+        let multisampled_view =
+            (attachments.multisampled).map(|texture| texture.create_view(&Default::default()));
+        let depth_stencil = (attachments.depth_stencil)
+            .map(|texture| (texture.create_view(&Default::default()), texture.format()));
+
         Self {
-            texture: Some(surface_texture),
+            texture: Some(texture),
             view,
+            multisampled_view,
+            depth_stencil,
         }
     }
 
-    pub fn texture(&self) -> &wgpu::SurfaceTexture {
-        self.texture.as_ref().unwrap()
+    pub fn texture(&self) -> &wgpu::Texture {
+        match self.texture.as_ref().unwrap() {
+            FrameTexture::Surface(surface_texture) => &surface_texture.texture,
+            FrameTexture::Offscreen(texture) => texture,
+        }
     }
 
     pub fn view(&self) -> &wgpu::TextureView {
         &self.view
     }
+
+    /// Draws into the multisampled texture and resolves it into the surface if the target uses
+    /// MSAA, otherwise draws into the surface directly.
+    pub fn color_attachment(
+        &self,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) -> wgpu::RenderPassColorAttachment<'_> {
+        self.color_attachment_to(&self.view, load)
+    }
+
+    /// Like [`Self::color_attachment`], but ends up in `view` instead of the surface, which has
+    /// to match it in size and format.
+    pub fn color_attachment_to<'s>(
+        &'s self,
+        view: &'s wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) -> wgpu::RenderPassColorAttachment<'s> {
+        let ops = wgpu::Operations {
+            load,
+            store: wgpu::StoreOp::Store,
+        };
+
+        match &self.multisampled_view {
+            Some(multisampled_view) => wgpu::RenderPassColorAttachment {
+                view: multisampled_view,
+                resolve_target: Some(view),
+                ops,
+            },
+
+            None => wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops,
+            },
+        }
+    }
+
+    /// Clears depth to 0 and stencil to 0, if the target has a depth/stencil buffer.
+    pub fn depth_stencil_attachment(&self) -> Option<wgpu::RenderPassDepthStencilAttachment<'_>> {
+        let (view, format) = self.depth_stencil.as_ref()?;
+
+        let depth_ops = format.has_depth_aspect().then_some(wgpu::Operations {
+            load: wgpu::LoadOp::Clear(0.),
+            store: wgpu::StoreOp::Store,
+        });
+        let stencil_ops = format.has_stencil_aspect().then_some(wgpu::Operations {
+            load: wgpu::LoadOp::Clear(0),
+            store: wgpu::StoreOp::Store,
+        });
+
+        Some(wgpu::RenderPassDepthStencilAttachment {
+            view,
+            depth_ops,
+            stencil_ops,
+        })
+    }
 }
 
 impl Drop for FrameSurface {
     fn drop(&mut self) {
-        let texture = self.texture.take().unwrap();
-        texture.present();
+        if let Some(FrameTexture::Surface(surface_texture)) = self.texture.take() {
+            surface_texture.present();
+        }
     }
 }
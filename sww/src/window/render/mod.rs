@@ -1,18 +1,26 @@
 use crate::window::*;
+use crate::ColorSpace;
 use event::*;
 use parking_lot::Mutex;
 use pollster::FutureExt;
 use std::sync::Arc;
 
 mod frame;
+mod target;
+mod texture;
 
 pub use frame::*;
+pub use target::*;
+pub use texture::*;
 
 pub struct RenderWindow {
     window: Arc<Window>,
     surface: wgpu::Surface<'static>,
     surface_config: Mutex<wgpu::SurfaceConfiguration>,
     swapchain_format: wgpu::TextureFormat,
+    sample_count: u32,
+    depth_stencil_format: Option<wgpu::TextureFormat>,
+    attachments: Mutex<FrameAttachments>,
     device: wgpu::Device,
     queue: wgpu::Queue,
 }
@@ -30,22 +38,50 @@ impl RenderWindow {
             .ok_or(AppInfoError::NoAdapter)?;
 
         let swapchain_capabilities = surface.get_capabilities(&adapter);
-        let swapchain_format = swapchain_capabilities.formats[0];
+        let is_srgb = settings.color_space() == ColorSpace::Srgb;
+        let swapchain_format = (swapchain_capabilities.formats.iter().copied())
+            .find(|format| format.is_srgb() == is_srgb)
+            .unwrap_or(swapchain_capabilities.formats[0]);
         let swapchain_alpha_mode = swapchain_capabilities.alpha_modes[0];
 
         let (device, queue) = adapter
             .request_device(&settings.device_descriptor(&adapter), None)
             .block_on()?;
 
-        let surface_config = settings.surface_config(size, swapchain_format, swapchain_alpha_mode);
+        let depth_stencil_format = settings.depth_stencil_format();
+        let sample_count = supported_sample_count(
+            &adapter,
+            &device,
+            swapchain_format,
+            depth_stencil_format,
+            settings.sample_count(),
+        );
+
+        let surface_config = settings.surface_config(
+            size,
+            swapchain_format,
+            swapchain_alpha_mode,
+            swapchain_capabilities.usages,
+        );
         surface.configure(&device, &surface_config);
         let surface_config = surface_config.into();
+        let attachments = create_attachments(
+            &device,
+            size,
+            swapchain_format,
+            sample_count,
+            depth_stencil_format,
+        )
+        .into();
 
         Ok(Self {
             window,
             surface,
             surface_config,
             swapchain_format,
+            sample_count,
+            depth_stencil_format,
+            attachments,
             device,
             queue,
         })
@@ -55,36 +91,133 @@ impl RenderWindow {
         &self.window
     }
 
-    pub fn device(&self) -> &wgpu::Device {
+    pub fn resize_surface(&self, new_size: IntSize) {
+        let mut surface_config = self.surface_config.lock();
+
+        surface_config.width = new_size.width.max(1);
+        surface_config.height = new_size.height.max(1);
+
+        self.surface.configure(&self.device, &surface_config);
+
+        let size = IntSize::new(surface_config.width, surface_config.height);
+        *self.attachments.lock() = create_attachments(
+            &self.device,
+            size,
+            self.swapchain_format,
+            self.sample_count,
+            self.depth_stencil_format,
+        );
+    }
+}
+
+impl RenderTarget for RenderWindow {
+    fn device(&self) -> &wgpu::Device {
         &self.device
     }
 
-    pub fn queue(&self) -> &wgpu::Queue {
+    fn queue(&self) -> &wgpu::Queue {
         &self.queue
     }
 
-    pub fn start_drawing(&self) -> Frame {
+    fn swapchain_format(&self) -> wgpu::TextureFormat {
+        self.swapchain_format
+    }
+
+    fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat> {
+        self.depth_stencil_format
+    }
+
+    fn size(&self) -> IntSize {
+        let surface_config = self.surface_config.lock();
+        IntSize::new(surface_config.width, surface_config.height)
+    }
+
+    fn start_drawing(&self) -> Frame<'_> {
         let command_encoder = self.device.create_command_encoder(&Default::default());
         let surface_texture = self
             .surface
             .get_current_texture()
             .expect("failed to get surface texture");
 
-        Frame::new(self, command_encoder, surface_texture)
+        let attachments = self.attachments.lock().clone();
+        Frame::new(self, command_encoder, surface_texture, attachments)
     }
+}
 
-    pub fn resize_surface(&self, new_size: IntSize) {
-        let mut surface_config = self.surface_config.lock();
-
-        surface_config.width = new_size.width.max(1);
-        surface_config.height = new_size.height.max(1);
+/// The highest sample count up to `requested` that both formats support on `device`.
+pub(super) fn supported_sample_count(
+    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    depth_stencil_format: Option<wgpu::TextureFormat>,
+    requested: u32,
+) -> u32 {
+    // adapter specific counts are only allowed with the feature, only 1 and 4 are guaranteed
+    let features = device.features();
+    let flags = |format: wgpu::TextureFormat| {
+        if features.contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES) {
+            adapter.get_texture_format_features(format).flags
+        } else {
+            format.guaranteed_format_features(features).flags
+        }
+    };
+
+    (flags(format).supported_sample_counts().into_iter())
+        .filter(|&count| count <= requested)
+        .filter(|&count| {
+            depth_stencil_format.is_none_or(|format| flags(format).sample_count_supported(count))
+        })
+        .max()
+        .unwrap_or(1)
+}
 
-        self.surface.configure(&self.device, &surface_config);
+/// Creates the multisampled colour texture if multisampling is used, and the depth/stencil
+/// texture if it has a format.
+pub(super) fn create_attachments(
+    device: &wgpu::Device,
+    size: IntSize,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    depth_stencil_format: Option<wgpu::TextureFormat>,
+) -> FrameAttachments {
+    let multisampled = (sample_count > 1)
+        .then(|| create_attachment(device, "multisampled", size, format, sample_count));
+    let depth_stencil = depth_stencil_format
+        .map(|format| create_attachment(device, "depth_stencil", size, format, sample_count));
+
+    FrameAttachments {
+        multisampled,
+        depth_stencil,
     }
+}
 
-    pub fn swapchain_format(&self) -> wgpu::TextureFormat {
-        self.swapchain_format
-    }
+fn create_attachment(
+    device: &wgpu::Device,
+    label: &str,
+    size: IntSize,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> Arc<wgpu::Texture> {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width: size.width.max(1),
+            height: size.height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+
+    Arc::new(texture)
 }
 
 pub fn rw_builder(settings: impl WindowSettings) -> impl Fn(&Arc<Window>) -> RenderWindow {
@@ -0,0 +1,16 @@
+use super::Frame;
+use crate::window::event::IntSize;
+
+/// Something that can be drawn into: either a [`RenderWindow`](super::RenderWindow) or an
+/// offscreen [`RenderTexture`](super::RenderTexture).
+pub trait RenderTarget {
+    fn device(&self) -> &wgpu::Device;
+    fn queue(&self) -> &wgpu::Queue;
+    fn swapchain_format(&self) -> wgpu::TextureFormat;
+    /// Number of MSAA samples, which pipelines drawing into this target have to match.
+    fn sample_count(&self) -> u32;
+    /// Format of the depth/stencil buffer, which pipelines drawing into this target have to match.
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat>;
+    fn size(&self) -> IntSize;
+    fn start_drawing(&self) -> Frame<'_>;
+}
@@ -0,0 +1,141 @@
+use super::create_attachments;
+use super::supported_sample_count;
+use crate::window::*;
+use event::*;
+use parking_lot::Mutex;
+use pollster::FutureExt;
+use std::sync::Arc;
+
+/// An offscreen render target, which requires neither a window nor a surface.
+pub struct RenderTexture {
+    texture: Mutex<Arc<wgpu::Texture>>,
+    format: wgpu::TextureFormat,
+    usage: wgpu::TextureUsages,
+    sample_count: u32,
+    depth_stencil_format: Option<wgpu::TextureFormat>,
+    attachments: Mutex<FrameAttachments>,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+impl RenderTexture {
+    pub fn new(settings: &impl RenderTextureSettings) -> Result<Self, AppInfoError> {
+        let instance = wgpu::Instance::new(settings.instance_descriptor());
+
+        let options = settings.request_adapter_options();
+        let adapter = (instance.request_adapter(&options).block_on())
+            .or_else(|| {
+                let options = wgpu::RequestAdapterOptions {
+                    force_fallback_adapter: true,
+                    ..options
+                };
+                instance.request_adapter(&options).block_on()
+            })
+            .ok_or(AppInfoError::NoAdapter)?;
+
+        let (device, queue) = adapter
+            .request_device(&settings.device_descriptor(&adapter), None)
+            .block_on()?;
+
+        let format = settings.format();
+        let usage = settings.usage();
+        let texture = create_texture(&device, settings.size(), format, usage);
+        let depth_stencil_format = settings.depth_stencil_format();
+        let sample_count = supported_sample_count(
+            &adapter,
+            &device,
+            format,
+            depth_stencil_format,
+            settings.sample_count(),
+        );
+        let attachments = create_attachments(
+            &device,
+            settings.size(),
+            format,
+            sample_count,
+            depth_stencil_format,
+        );
+
+        Ok(Self {
+            texture: Mutex::new(Arc::new(texture)),
+            format,
+            usage,
+            sample_count,
+            depth_stencil_format,
+            attachments: Mutex::new(attachments),
+            device,
+            queue,
+        })
+    }
+
+    pub fn texture(&self) -> Arc<wgpu::Texture> {
+        Arc::clone(&self.texture.lock())
+    }
+
+    pub fn resize(&self, new_size: IntSize) {
+        let texture = create_texture(&self.device, new_size, self.format, self.usage);
+        *self.texture.lock() = Arc::new(texture);
+        *self.attachments.lock() = create_attachments(
+            &self.device,
+            new_size,
+            self.format,
+            self.sample_count,
+            self.depth_stencil_format,
+        );
+    }
+}
+
+impl RenderTarget for RenderTexture {
+    fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    fn swapchain_format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat> {
+        self.depth_stencil_format
+    }
+
+    fn size(&self) -> IntSize {
+        let size = self.texture.lock().size();
+        IntSize::new(size.width, size.height)
+    }
+
+    fn start_drawing(&self) -> Frame<'_> {
+        let command_encoder = self.device.create_command_encoder(&Default::default());
+        let attachments = self.attachments.lock().clone();
+        Frame::new_offscreen(self, command_encoder, self.texture(), attachments)
+    }
+}
+
+fn create_texture(
+    device: &wgpu::Device,
+    size: IntSize,
+    format: wgpu::TextureFormat,
+    usage: wgpu::TextureUsages,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("RenderTexture"),
+        size: wgpu::Extent3d {
+            width: size.width.max(1),
+            height: size.height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage,
+        view_formats: &[],
+    })
+}
@@ -1,9 +1,25 @@
 use super::WindowAttributes;
 use crate::window::event::IntSize;
+use crate::ColorSpace;
 
 pub trait WindowSettings {
     fn window_attributes(&self) -> WindowAttributes;
 
+    /// A swapchain format of this colour space is preferred, if the surface supports one.
+    fn color_space(&self) -> ColorSpace {
+        ColorSpace::default()
+    }
+
+    /// Number of MSAA samples per pixel. Lowered to the closest count the adapter supports.
+    fn sample_count(&self) -> u32 {
+        1
+    }
+
+    /// Format of the depth/stencil buffer, which is only created if this is set.
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat> {
+        None
+    }
+
     fn instance_descriptor(&self) -> wgpu::InstanceDescriptor {
         Default::default()
     }
@@ -23,24 +39,30 @@ pub trait WindowSettings {
         Default::default()
     }
 
-    fn device_descriptor(&self, adapter: &wgpu::Adapter) -> wgpu::DeviceDescriptor {
+    fn device_descriptor(&self, adapter: &wgpu::Adapter) -> wgpu::DeviceDescriptor<'_> {
         wgpu::DeviceDescriptor {
             label: None,
-            required_features: wgpu::Features::default(),
+            // lets multisampling use all sample counts the adapter supports
+            required_features: adapter.features()
+                & wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES,
             required_limits: wgpu::Limits::downlevel_webgl2_defaults()
                 .using_resolution(adapter.limits()),
             memory_hints: self.memory_hints(),
         }
     }
 
+    /// `supported_usages` are the ones of the surface capabilities.
     fn surface_config(
         &self,
         size: IntSize,
         swapchain_format: wgpu::TextureFormat,
         swapchain_alpha_mode: wgpu::CompositeAlphaMode,
+        supported_usages: wgpu::TextureUsages,
     ) -> wgpu::SurfaceConfiguration {
         wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            // copying into the surface is optional, it lets frames be drawn elsewhere first
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | (supported_usages & wgpu::TextureUsages::COPY_DST),
             format: swapchain_format,
             width: size.width,
             height: size.height,
@@ -51,3 +73,61 @@ pub trait WindowSettings {
         }
     }
 }
+
+pub trait RenderTextureSettings {
+    fn size(&self) -> IntSize;
+
+    fn color_space(&self) -> ColorSpace {
+        ColorSpace::default()
+    }
+
+    /// Number of MSAA samples per pixel. Lowered to the closest count the adapter supports.
+    fn sample_count(&self) -> u32 {
+        1
+    }
+
+    /// Format of the depth/stencil buffer, which is only created if this is set.
+    fn depth_stencil_format(&self) -> Option<wgpu::TextureFormat> {
+        None
+    }
+
+    fn format(&self) -> wgpu::TextureFormat {
+        self.color_space().apply(wgpu::TextureFormat::Rgba8Unorm)
+    }
+
+    fn usage(&self) -> wgpu::TextureUsages {
+        wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::COPY_DST
+    }
+
+    fn instance_descriptor(&self) -> wgpu::InstanceDescriptor {
+        Default::default()
+    }
+
+    /// No surface is required, so a fallback (software) adapter is requested if there's no other.
+    fn request_adapter_options(&self) -> wgpu::RequestAdapterOptions<'static, 'static> {
+        wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        }
+    }
+
+    fn memory_hints(&self) -> wgpu::MemoryHints {
+        Default::default()
+    }
+
+    fn device_descriptor(&self, adapter: &wgpu::Adapter) -> wgpu::DeviceDescriptor<'_> {
+        wgpu::DeviceDescriptor {
+            label: None,
+            // lets multisampling use all sample counts the adapter supports
+            required_features: adapter.features()
+                & wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES,
+            required_limits: wgpu::Limits::downlevel_webgl2_defaults()
+                .using_resolution(adapter.limits()),
+            memory_hints: self.memory_hints(),
+        }
+    }
+}